@@ -0,0 +1,74 @@
+use syn::{Attribute, ExprPath, ExprRange, LitInt};
+
+/// `#[random(...)]` options on an enum variant
+pub(crate) struct VariantAttributes {
+    pub(crate) weight: u32,
+    pub(crate) skip: bool,
+}
+
+impl VariantAttributes {
+    pub(crate) fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut attributes = Self {
+            weight: 1,
+            skip: false,
+        };
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("random")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("weight") {
+                    let weight: LitInt = meta.value()?.parse()?;
+                    attributes.weight = weight.base10_parse()?;
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    attributes.skip = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `weight = <integer>` or `skip`"))
+                }
+            })?;
+        }
+
+        Ok(attributes)
+    }
+}
+
+/// How a field gets its value, from `#[random(...)]` options on the field
+pub(crate) enum FieldStrategy {
+    /// `Random::random()`, or `RandomRange::random_range` between the bounds' fields
+    Random,
+    /// `#[random(range = low..high)]`
+    Range(ExprRange),
+    /// `#[random(with = path::to::function)]`
+    With(ExprPath),
+    /// `#[random(default)]`
+    Default,
+}
+
+impl FieldStrategy {
+    pub(crate) fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut strategy = Self::Random;
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("random")) {
+            attr.parse_nested_meta(|meta| {
+                if !matches!(strategy, Self::Random) {
+                    return Err(meta.error("only one of `range`, `with` or `default` can be used"));
+                }
+
+                if meta.path.is_ident("range") {
+                    strategy = Self::Range(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("with") {
+                    strategy = Self::With(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    strategy = Self::Default;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `range = <range>`, `with = <function>` or `default`"))
+                }
+            })?;
+        }
+
+        Ok(strategy)
+    }
+}
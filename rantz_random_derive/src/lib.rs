@@ -0,0 +1,202 @@
+//! Derive macros for [rantz_random](https://docs.rs/rantz_random).
+//!
+//! These are re-exported by `rantz_random` behind the `derive` feature, use them from there.
+
+mod attributes;
+
+use attributes::{FieldStrategy, VariantAttributes};
+use proc_macro::TokenStream;
+use proc_macro2::{Literal, Span, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned};
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, Data, DataEnum, DeriveInput, Error, Field,
+    Fields, Generics, Index, Member, Path,
+};
+
+/// Derives `rantz_random::Random`.
+///
+/// Structs are built by calling `Random::random` for every field.
+/// Enums pick a variant, uniformly unless weighted, and then build it the same way as a struct.
+///
+/// Variants can be given a relative weight with `#[random(weight = 5)]` (the default is 1),
+/// or excluded with `#[random(skip)]`.
+///
+/// Fields can override how they're generated:
+/// - `#[random(range = 1..=10)]` uses `RandomIn::random_in`, any range syntax works
+/// - `#[random(with = my_fn)]` calls `my_fn()`
+/// - `#[random(default)]` uses `Default::default()`
+#[proc_macro_derive(Random, attributes(random))]
+pub fn derive_random(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_random(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives `rantz_random::RandomRange` for structs.
+///
+/// Every field is generated with `RandomRange::random_range` between the matching fields of `low` and `high`.
+/// Fields marked `#[random(with = my_fn)]` or `#[random(default)]` ignore the bounds.
+/// `#[random(range = ...)]` only applies to the `Random` derive and is an error here.
+#[proc_macro_derive(RandomRange, attributes(random))]
+pub fn derive_random_range(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_random_range(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_random(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let generics = add_trait_bounds(input.generics.clone(), parse_quote!(::rantz_random::Random));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => construct(quote!(Self), &data.fields, random_field)?,
+        Data::Enum(data) => random_variant(data, input.ident.span())?,
+        Data::Union(_) => {
+            return Err(Error::new(
+                input.ident.span(),
+                "Random can't be derived for unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::rantz_random::Random for #name #ty_generics #where_clause {
+            fn random() -> Self {
+                #body
+            }
+        }
+    })
+}
+
+fn expand_random_range(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let generics = add_trait_bounds(
+        input.generics.clone(),
+        parse_quote!(::rantz_random::RandomRange),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => construct(quote!(Self), &data.fields, random_range_field)?,
+        _ => {
+            return Err(Error::new(
+                input.ident.span(),
+                "RandomRange can only be derived for structs",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::rantz_random::RandomRange for #name #ty_generics #where_clause {
+            fn random_range(low: Self, high: Self) -> Self {
+                #body
+            }
+        }
+    })
+}
+
+fn add_trait_bounds(mut generics: Generics, bound: Path) -> Generics {
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#bound));
+    }
+    generics
+}
+
+fn construct(
+    path: TokenStream2,
+    fields: &Fields,
+    value: fn(&Field, &Member) -> syn::Result<TokenStream2>,
+) -> syn::Result<TokenStream2> {
+    let fields = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let member = match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(Index::from(i)),
+            };
+            let value = value(field, &member)?;
+            Ok(quote_spanned!(field.span()=> #member: #value))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote!(#path { #(#fields),* }))
+}
+
+fn random_field(field: &Field, _: &Member) -> syn::Result<TokenStream2> {
+    let ty = &field.ty;
+    Ok(match FieldStrategy::parse(&field.attrs)? {
+        FieldStrategy::Random => quote!(<#ty as ::rantz_random::Random>::random()),
+        FieldStrategy::Range(range) => quote!(<#ty as ::rantz_random::RandomIn>::random_in(#range)),
+        FieldStrategy::With(function) => quote!(#function()),
+        FieldStrategy::Default => quote!(<#ty as ::core::default::Default>::default()),
+    })
+}
+
+fn random_range_field(field: &Field, member: &Member) -> syn::Result<TokenStream2> {
+    let ty = &field.ty;
+    Ok(match FieldStrategy::parse(&field.attrs)? {
+        FieldStrategy::Random => {
+            quote!(<#ty as ::rantz_random::RandomRange>::random_range(low.#member, high.#member))
+        }
+        FieldStrategy::Range(range) => {
+            return Err(Error::new_spanned(
+                range,
+                "range has no effect on RandomRange; use Random",
+            ))
+        }
+        FieldStrategy::With(function) => quote!(#function()),
+        FieldStrategy::Default => quote!(<#ty as ::core::default::Default>::default()),
+    })
+}
+
+fn random_variant(data: &DataEnum, span: Span) -> syn::Result<TokenStream2> {
+    let mut variants = Vec::new();
+    let mut total: u64 = 0;
+
+    for variant in &data.variants {
+        let attributes = VariantAttributes::parse(&variant.attrs)?;
+        if attributes.skip || attributes.weight == 0 {
+            continue;
+        }
+
+        let low = total + 1;
+        total += attributes.weight as u64;
+        variants.push((low, total, variant));
+    }
+
+    if total == 0 {
+        return Err(Error::new(
+            span,
+            "Random can't be derived for an enum without any selectable variants",
+        ));
+    }
+    if total > u32::MAX as u64 {
+        return Err(Error::new(
+            span,
+            "the total weight of all variants must fit in a u32",
+        ));
+    }
+
+    let arms = variants
+        .into_iter()
+        .map(|(low, high, variant)| {
+            let low = Literal::u32_suffixed(low as u32);
+            let high = Literal::u32_suffixed(high as u32);
+            let ident = &variant.ident;
+            let value = construct(quote!(Self::#ident), &variant.fields, random_field)?;
+            Ok(quote!(#low..=#high => #value,))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    let total = Literal::u32_suffixed(total as u32);
+
+    Ok(quote! {
+        match <u32 as ::rantz_random::RandomRange>::random_range(1, #total) {
+            #(#arms)*
+            _ => unreachable!(),
+        }
+    })
+}
@@ -0,0 +1,7 @@
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn rejected_attributes() {
+        trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+    }
+}
@@ -0,0 +1,9 @@
+use rantz_random_derive::RandomRange;
+
+#[derive(RandomRange)]
+struct Stats {
+    #[random(range = 10..=20)]
+    hp: u32,
+}
+
+fn main() {}
@@ -0,0 +1,198 @@
+//! Angle
+//!
+//! Picking angles from arcs of the circle, including arcs that cross zero,
+//! like a turret that can sweep from 350° round to 10°:
+//!
+//! ```rust
+//! use rantz_random::angle::AngleArc;
+//! use rantz_random::Distribution;
+//!
+//! let sweep = AngleArc::counterclockwise(350.0f32.to_radians(), 10.0f32.to_radians());
+//! let aim = sweep.sample_random();
+//! assert!(sweep.contains(aim));
+//!
+//! // Within 15° either side of straight up
+//! let spray = AngleArc::around(90.0f32.to_radians(), 15.0f32.to_radians());
+//! ```
+//!
+//! Plain `f32` and `f64` angles are in radians, with the `spatial2d` feature
+//! [Degrees](rantz_spatial2d::prelude::Degrees) and [Radians](rantz_spatial2d::prelude::Radians) work too.
+//! For angles clustered around a heading rather than spread evenly over an arc,
+//! see [VonMises](crate::distributions::VonMises) and [WrappedNormal](crate::distributions::WrappedNormal).
+
+use crate::distributions::Distribution;
+use std::f64::consts::TAU;
+use std::marker::PhantomData;
+
+/// Types that measure an angle.
+pub trait Angle: Copy + private::Sealed {
+    /// One full turn in this type's units.
+    const FULL_TURN: f64;
+
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+}
+
+impl Angle for f32 {
+    const FULL_TURN: f64 = TAU;
+
+    fn from_f64(value: f64) -> f32 {
+        value as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl Angle for f64 {
+    const FULL_TURN: f64 = TAU;
+
+    fn from_f64(value: f64) -> f64 {
+        value
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+#[cfg(feature = "spatial2d")]
+mod spatial2d {
+    use super::{private, Angle, TAU};
+    use rantz_spatial2d::prelude::*;
+
+    impl Angle for Degrees {
+        const FULL_TURN: f64 = 360.0;
+
+        fn from_f64(value: f64) -> Degrees {
+            Degrees::new(value as f32)
+        }
+
+        fn to_f64(self) -> f64 {
+            self.to_f32() as f64
+        }
+    }
+
+    impl Angle for Radians {
+        const FULL_TURN: f64 = TAU;
+
+        fn from_f64(value: f64) -> Radians {
+            Radians::new(value as f32)
+        }
+
+        fn to_f64(self) -> f64 {
+            self.to_f32() as f64
+        }
+    }
+
+    impl private::Sealed for Degrees {}
+    impl private::Sealed for Radians {}
+}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// An arc of the circle, every angle along it equally likely.
+///
+/// Angles are measured on from the start of the arc, so they can land below zero or past a full turn,
+/// `350°..10°` gives values from `350°` to `370°`. They still point the right way, wrap them if that matters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AngleArc<A> {
+    start: f64,
+    sweep: f64,
+    marker: PhantomData<A>,
+}
+
+impl<A: Angle> AngleArc<A> {
+    /// Turning counterclockwise from `from` until reaching `to`.
+    ///
+    /// `from` and `to` a whole number of turns apart, like `0°` and `360°`, is the whole circle.
+    pub fn counterclockwise(from: A, to: A) -> Self {
+        let (from, to) = (from.to_f64(), to.to_f64());
+        Self::new(from, arc_length::<A>(to - from))
+    }
+
+    /// Turning clockwise from `from` until reaching `to`.
+    ///
+    /// `from` and `to` a whole number of turns apart, like `0°` and `360°`, is the whole circle.
+    pub fn clockwise(from: A, to: A) -> Self {
+        let (from, to) = (from.to_f64(), to.to_f64());
+        Self::new(from, -arc_length::<A>(from - to))
+    }
+
+    /// Whichever way round from `from` to `to` is shorter, counterclockwise if they're exactly opposite.
+    pub fn shortest(from: A, to: A) -> Self {
+        let (from, to) = (from.to_f64(), to.to_f64());
+        let mut sweep = (to - from).rem_euclid(A::FULL_TURN);
+        if sweep > A::FULL_TURN / 2.0 {
+            sweep -= A::FULL_TURN;
+        }
+        Self::new(from, sweep)
+    }
+
+    /// Up to `spread` either side of `center`, the whole circle once `spread` reaches half a turn.
+    pub fn around(center: A, spread: A) -> Self {
+        let spread = spread.to_f64().abs().min(A::FULL_TURN / 2.0);
+        Self::new(center.to_f64() - spread, 2.0 * spread)
+    }
+
+    /// The whole circle, starting from zero.
+    pub fn full() -> Self {
+        Self::new(0.0, A::FULL_TURN)
+    }
+
+    fn new(start: f64, sweep: f64) -> Self {
+        Self {
+            start,
+            sweep,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn start(&self) -> A {
+        A::from_f64(self.start)
+    }
+
+    pub fn end(&self) -> A {
+        A::from_f64(self.start + self.sweep)
+    }
+
+    /// How far the arc turns, negative for clockwise arcs.
+    pub fn sweep(&self) -> A {
+        A::from_f64(self.sweep)
+    }
+
+    /// Whether `angle` points somewhere along the arc, in any turn.
+    pub fn contains(&self, angle: A) -> bool {
+        let length = self.sweep.abs();
+        if length >= A::FULL_TURN {
+            return true;
+        }
+        let offset = (angle.to_f64() - self.start) * self.sweep.signum();
+        // A little slack so the ends still count after a round trip through f32
+        let slack = A::FULL_TURN * 1e-6;
+        let offset = (offset + slack).rem_euclid(A::FULL_TURN) - slack;
+        offset <= length + slack
+    }
+}
+
+impl<A: Angle> Distribution<A> for AngleArc<A> {
+    fn sample(&self, rng: &mut fastrand::Rng) -> A {
+        A::from_f64(self.start + self.sweep * rng.f64())
+    }
+}
+
+/// How far counterclockwise `difference` turns, a nonzero whole number of turns is a full turn.
+fn arc_length<A: Angle>(difference: f64) -> f64 {
+    let length = difference.rem_euclid(A::FULL_TURN);
+    // f32 constants like TAU round up, so `0.0..TAU` lands just past a whole turn
+    if length <= A::FULL_TURN * 1e-6 && difference.abs() >= A::FULL_TURN / 2.0 {
+        A::FULL_TURN
+    } else {
+        length
+    }
+}
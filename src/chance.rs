@@ -0,0 +1,110 @@
+//! Chance
+//!
+//! Yes or no rolls at a given probability, plus graded skill checks:
+//!
+//! ```rust
+//! use rantz_random::chance::{chance, one_in, percent, roll_check, CheckOutcome};
+//!
+//! let dodged = chance(0.35);
+//! let found_rare_loot = one_in(20);
+//! let critical_hit = percent(15);
+//!
+//! match roll_check(4, 15) {
+//!     CheckOutcome::CriticalSuccess => println!("The lock springs open instantly"),
+//!     CheckOutcome::Success => println!("The lock opens"),
+//!     CheckOutcome::Failure => println!("The lock holds"),
+//!     CheckOutcome::CriticalFailure => println!("The pick snaps"),
+//! }
+//! ```
+//!
+//! Every helper has a `_with` version that takes an explicit [Rng](crate::Rng) so rolls can be replayed from a seed.
+
+use crate::distributions::global_rng;
+
+/// Sides on the die used by [roll_check].
+pub const CHECK_DIE: i32 = 20;
+
+/// How well a [roll_check] went.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CheckOutcome {
+    CriticalFailure,
+    Failure,
+    Success,
+    CriticalSuccess,
+}
+
+impl CheckOutcome {
+    /// `true` for [Success](Self::Success) and [CriticalSuccess](Self::CriticalSuccess).
+    pub fn is_success(self) -> bool {
+        matches!(self, CheckOutcome::Success | CheckOutcome::CriticalSuccess)
+    }
+
+    /// `true` for [CriticalFailure](Self::CriticalFailure) and [CriticalSuccess](Self::CriticalSuccess).
+    pub fn is_critical(self) -> bool {
+        matches!(
+            self,
+            CheckOutcome::CriticalFailure | CheckOutcome::CriticalSuccess
+        )
+    }
+}
+
+/// `true` with probability `p`, anything at or below `0.0` (or NaN) never happens and anything at or above `1.0` always does.
+pub fn chance(p: f64) -> bool {
+    chance_with(&mut global_rng(), p)
+}
+
+/// [chance] using `rng`.
+pub fn chance_with(rng: &mut fastrand::Rng, p: f64) -> bool {
+    rng.f64() < p
+}
+
+/// `true` once in every `n` rolls on average, `one_in(0)` is never `true`.
+pub fn one_in(n: u32) -> bool {
+    one_in_with(&mut global_rng(), n)
+}
+
+/// [one_in] using `rng`.
+pub fn one_in_with(rng: &mut fastrand::Rng, n: u32) -> bool {
+    n != 0 && rng.u32(0..n) == 0
+}
+
+/// `true` at odds of `in_favour` to `against`, so `odds(3, 7)` happens 3 times in 10.
+///
+/// `odds(0, 0)` is never `true`.
+pub fn odds(in_favour: u32, against: u32) -> bool {
+    odds_with(&mut global_rng(), in_favour, against)
+}
+
+/// [odds] using `rng`.
+pub fn odds_with(rng: &mut fastrand::Rng, in_favour: u32, against: u32) -> bool {
+    let total = in_favour as u64 + against as u64;
+    total != 0 && rng.u64(0..total) < in_favour as u64
+}
+
+/// `true` `percent` times in 100, anything above 100 always happens.
+pub fn percent(percent: u32) -> bool {
+    percent_with(&mut global_rng(), percent)
+}
+
+/// [percent] using `rng`.
+pub fn percent_with(rng: &mut fastrand::Rng, percent: u32) -> bool {
+    rng.u32(0..100) < percent
+}
+
+/// Rolls a d20 and adds `skill`, succeeding if the total meets or beats `difficulty`.
+///
+/// A natural 20 is always a [CriticalSuccess](CheckOutcome::CriticalSuccess)
+/// and a natural 1 is always a [CriticalFailure](CheckOutcome::CriticalFailure).
+pub fn roll_check(skill: i32, difficulty: i32) -> CheckOutcome {
+    roll_check_with(&mut global_rng(), skill, difficulty)
+}
+
+/// [roll_check] using `rng`.
+pub fn roll_check_with(rng: &mut fastrand::Rng, skill: i32, difficulty: i32) -> CheckOutcome {
+    match rng.i32(1..=CHECK_DIE) {
+        1 => CheckOutcome::CriticalFailure,
+        CHECK_DIE => CheckOutcome::CriticalSuccess,
+        roll if roll.saturating_add(skill) >= difficulty => CheckOutcome::Success,
+        _ => CheckOutcome::Failure,
+    }
+}
@@ -0,0 +1,392 @@
+//! Color
+//!
+//! Random colors that look chosen rather than thrown together.
+//! [Random](crate::Random) for [Color] picks each sRGB channel independently, which mostly gives muddy browns and greys.
+//! Sampling in HSL, HSV or Oklch instead keeps saturation and lightness under control:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use rantz_random::color::OklchRange;
+//! use rantz_random::Distribution;
+//!
+//! // Bright, fairly saturated reds through oranges, hue ranges can cross 0°
+//! let embers = OklchRange::new()
+//!     .with_hue(340.0, 60.0)
+//!     .with_lightness(0.6, 0.8)
+//!     .with_chroma(0.12, 0.18);
+//! let ember: Color = embers.sample_random();
+//! ```
+//!
+//! [Palette] builds whole sets of colors that go together, and a palette collects into a
+//! [WeightedTable] for picking some of its colors more often than others:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use rantz_random::color::{HueScheme, Palette};
+//! use rantz_random::RandomWeightedContainer;
+//!
+//! let team_colors = Palette::new(HueScheme::GoldenRatio).generate(6);
+//!
+//! let flowers = Palette::new(HueScheme::Triadic).generate_weighted(&[6, 3, 1]);
+//! let flower = flowers.weighted_random();
+//! ```
+//!
+//! Hues are in degrees, lightness, saturation and value in `0.0..=1.0`.
+//! Oklch colors with high chroma can fall outside what sRGB displays and get clipped,
+//! keeping chroma around `0.15` or below stays inside for most hues.
+
+use crate::angle::AngleArc;
+use crate::distributions::{global_rng, Distribution};
+use crate::WeightedTable;
+use bevy::prelude::*;
+
+/// How far round the hue wheel each color of a [HueScheme::GoldenRatio] palette moves on, in degrees.
+const GOLDEN_ANGLE: f32 = 137.507_76;
+
+/// Colors drawn uniformly from a range of each HSL component.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HslRange {
+    hue: (f32, f32),
+    saturation: (f32, f32),
+    lightness: (f32, f32),
+}
+
+impl Default for HslRange {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HslRange {
+    /// Every hue, saturation and lightness.
+    pub fn new() -> Self {
+        Self {
+            hue: (0.0, 360.0),
+            saturation: (0.0, 1.0),
+            lightness: (0.0, 1.0),
+        }
+    }
+
+    /// Hues counterclockwise from `from` to `to`, crossing 0° if `from` is the larger.
+    pub fn with_hue(mut self, from: f32, to: f32) -> Self {
+        self.hue = (from, to);
+        self
+    }
+
+    pub fn with_saturation(mut self, min: f32, max: f32) -> Self {
+        self.saturation = (min, max);
+        self
+    }
+
+    pub fn with_lightness(mut self, min: f32, max: f32) -> Self {
+        self.lightness = (min, max);
+        self
+    }
+}
+
+impl Distribution<Hsla> for HslRange {
+    fn sample(&self, rng: &mut fastrand::Rng) -> Hsla {
+        Hsla::hsl(
+            hue_in(rng, self.hue),
+            between(rng, self.saturation),
+            between(rng, self.lightness),
+        )
+    }
+}
+
+impl Distribution<Color> for HslRange {
+    fn sample(&self, rng: &mut fastrand::Rng) -> Color {
+        Color::from(Distribution::<Hsla>::sample(self, rng))
+    }
+}
+
+/// Colors drawn uniformly from a range of each HSV component.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HsvRange {
+    hue: (f32, f32),
+    saturation: (f32, f32),
+    value: (f32, f32),
+}
+
+impl Default for HsvRange {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HsvRange {
+    /// Every hue, saturation and value.
+    pub fn new() -> Self {
+        Self {
+            hue: (0.0, 360.0),
+            saturation: (0.0, 1.0),
+            value: (0.0, 1.0),
+        }
+    }
+
+    /// Hues counterclockwise from `from` to `to`, crossing 0° if `from` is the larger.
+    pub fn with_hue(mut self, from: f32, to: f32) -> Self {
+        self.hue = (from, to);
+        self
+    }
+
+    pub fn with_saturation(mut self, min: f32, max: f32) -> Self {
+        self.saturation = (min, max);
+        self
+    }
+
+    pub fn with_value(mut self, min: f32, max: f32) -> Self {
+        self.value = (min, max);
+        self
+    }
+}
+
+impl Distribution<Hsva> for HsvRange {
+    fn sample(&self, rng: &mut fastrand::Rng) -> Hsva {
+        Hsva::hsv(
+            hue_in(rng, self.hue),
+            between(rng, self.saturation),
+            between(rng, self.value),
+        )
+    }
+}
+
+impl Distribution<Color> for HsvRange {
+    fn sample(&self, rng: &mut fastrand::Rng) -> Color {
+        Color::from(Distribution::<Hsva>::sample(self, rng))
+    }
+}
+
+/// Colors drawn uniformly from a range of each Oklch component.
+///
+/// Oklch is perceptually uniform, so colors with the same lightness look equally bright whatever their hue,
+/// which HSL and HSV don't manage.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OklchRange {
+    hue: (f32, f32),
+    lightness: (f32, f32),
+    chroma: (f32, f32),
+}
+
+impl Default for OklchRange {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OklchRange {
+    /// Every hue and lightness, with chroma up to `0.15`.
+    pub fn new() -> Self {
+        Self {
+            hue: (0.0, 360.0),
+            lightness: (0.0, 1.0),
+            chroma: (0.0, 0.15),
+        }
+    }
+
+    /// Hues counterclockwise from `from` to `to`, crossing 0° if `from` is the larger.
+    pub fn with_hue(mut self, from: f32, to: f32) -> Self {
+        self.hue = (from, to);
+        self
+    }
+
+    pub fn with_lightness(mut self, min: f32, max: f32) -> Self {
+        self.lightness = (min, max);
+        self
+    }
+
+    pub fn with_chroma(mut self, min: f32, max: f32) -> Self {
+        self.chroma = (min, max);
+        self
+    }
+}
+
+impl Distribution<Oklcha> for OklchRange {
+    fn sample(&self, rng: &mut fastrand::Rng) -> Oklcha {
+        Oklcha::lch(
+            between(rng, self.lightness),
+            between(rng, self.chroma),
+            hue_in(rng, self.hue),
+        )
+    }
+}
+
+impl Distribution<Color> for OklchRange {
+    fn sample(&self, rng: &mut fastrand::Rng) -> Color {
+        Color::from(Distribution::<Oklcha>::sample(self, rng))
+    }
+}
+
+/// How the hues of a [Palette] relate to each other.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HueScheme {
+    /// Each hue a golden angle, about 137.5°, on from the last, as spread out as possible for any number of colors.
+    GoldenRatio,
+    /// Hues evenly spread up to this many degrees either side of the base hue.
+    Analogous(f32),
+    /// The base hue and the one opposite it.
+    Complementary,
+    /// Three hues 120° apart.
+    Triadic,
+}
+
+impl HueScheme {
+    fn hue(self, base: f32, index: usize, count: usize) -> f32 {
+        let offset = match self {
+            HueScheme::GoldenRatio => index as f32 * GOLDEN_ANGLE,
+            HueScheme::Analogous(spread) if count > 1 => {
+                spread * (2.0 * index as f32 / (count - 1) as f32 - 1.0)
+            }
+            HueScheme::Analogous(_) => 0.0,
+            HueScheme::Complementary => (index % 2) as f32 * 180.0,
+            HueScheme::Triadic => (index % 3) as f32 * 120.0,
+        };
+        (base + offset).rem_euclid(360.0)
+    }
+}
+
+/// A set of colors whose hues follow a [HueScheme], generated in Oklch.
+///
+/// The base hue is random unless set, lightness and chroma are drawn for each color from their ranges.
+/// Schemes with fewer hues than colors repeat them, with different lightness and chroma.
+///
+/// ```rust
+/// use rantz_random::color::{HueScheme, Palette};
+///
+/// let forest = Palette::new(HueScheme::Analogous(30.0))
+///     .with_base_hue(140.0)
+///     .with_lightness(0.35, 0.65)
+///     .generate(5);
+/// assert_eq!(forest.len(), 5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Palette {
+    scheme: HueScheme,
+    base_hue: Option<f32>,
+    lightness: (f32, f32),
+    chroma: (f32, f32),
+}
+
+impl Palette {
+    /// Lightness between `0.55` and `0.8` and chroma between `0.1` and `0.15`, bright without glaring.
+    pub fn new(scheme: HueScheme) -> Self {
+        Self {
+            scheme,
+            base_hue: None,
+            lightness: (0.55, 0.8),
+            chroma: (0.1, 0.15),
+        }
+    }
+
+    pub fn with_base_hue(mut self, hue: f32) -> Self {
+        self.base_hue = Some(hue);
+        self
+    }
+
+    pub fn with_lightness(mut self, min: f32, max: f32) -> Self {
+        self.lightness = (min, max);
+        self
+    }
+
+    pub fn with_chroma(mut self, min: f32, max: f32) -> Self {
+        self.chroma = (min, max);
+        self
+    }
+
+    /// `count` colors, in order around the scheme.
+    pub fn generate(&self, count: usize) -> Vec<Color> {
+        self.generate_with(count, &mut global_rng())
+    }
+
+    /// [generate](Self::generate) using `rng`.
+    pub fn generate_with(&self, count: usize, rng: &mut fastrand::Rng) -> Vec<Color> {
+        let base = self.base_hue.unwrap_or_else(|| rng.f32() * 360.0);
+        (0..count)
+            .map(|index| {
+                Color::oklch(
+                    between(rng, self.lightness),
+                    between(rng, self.chroma),
+                    self.scheme.hue(base, index, count),
+                )
+            })
+            .collect()
+    }
+
+    /// One color per weight, each picked in proportion to its weight.
+    pub fn generate_weighted(&self, weights: &[u32]) -> WeightedTable<Color> {
+        self.generate_weighted_with(weights, &mut global_rng())
+    }
+
+    /// [generate_weighted](Self::generate_weighted) using `rng`.
+    pub fn generate_weighted_with(
+        &self,
+        weights: &[u32],
+        rng: &mut fastrand::Rng,
+    ) -> WeightedTable<Color> {
+        self.generate_with(weights.len(), rng)
+            .into_iter()
+            .zip(weights)
+            .collect()
+    }
+}
+
+/// Small random changes to a color, in Oklch so every hue shifts by the same perceived amount.
+///
+/// Good for variety across many copies of one thing, like leaves on a tree or bricks in a wall:
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use rantz_random::color::ColorJitter;
+///
+/// let brick = Color::srgb(0.6, 0.25, 0.2);
+/// let bricks: Vec<Color> = (0..50).map(|_| ColorJitter::new(8.0, 0.05, 0.02).jitter(brick)).collect();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorJitter {
+    hue: f32,
+    lightness: f32,
+    chroma: f32,
+}
+
+impl ColorJitter {
+    /// Shifts each component by up to its amount either way, `hue` in degrees.
+    pub fn new(hue: f32, lightness: f32, chroma: f32) -> Self {
+        Self {
+            hue,
+            lightness,
+            chroma,
+        }
+    }
+
+    /// `color` shifted by a random amount, keeping its alpha.
+    pub fn jitter(&self, color: Color) -> Color {
+        self.jitter_with(color, &mut global_rng())
+    }
+
+    /// [jitter](Self::jitter) using `rng`.
+    pub fn jitter_with(&self, color: Color, rng: &mut fastrand::Rng) -> Color {
+        let base = Oklcha::from(color);
+        Color::from(Oklcha::new(
+            (base.lightness + spread(rng, self.lightness)).clamp(0.0, 1.0),
+            (base.chroma + spread(rng, self.chroma)).max(0.0),
+            (base.hue + spread(rng, self.hue)).rem_euclid(360.0),
+            base.alpha,
+        ))
+    }
+}
+
+/// Uniform in `min..max`.
+fn between(rng: &mut fastrand::Rng, (min, max): (f32, f32)) -> f32 {
+    min + (max - min) * rng.f32()
+}
+
+/// Uniform in `-amount..amount`.
+fn spread(rng: &mut fastrand::Rng, amount: f32) -> f32 {
+    amount * (2.0 * rng.f32() - 1.0)
+}
+
+/// A hue counterclockwise from `from` to `to` in degrees, wrapped into `0.0..360.0`.
+fn hue_in(rng: &mut fastrand::Rng, (from, to): (f32, f32)) -> f32 {
+    let arc = AngleArc::counterclockwise(from.to_radians(), to.to_radians());
+    arc.sample(rng).to_degrees().rem_euclid(360.0)
+}
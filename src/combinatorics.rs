@@ -0,0 +1,157 @@
+//! Combinatorics
+//!
+//! Uniformly random permutations, derangements, combinations and set partitions of the indices `0..n`.
+//! The results are plain index vectors so they can be applied to any collection:
+//!
+//! ```rust
+//! use rantz_random::combinatorics::derangement;
+//!
+//! let players = ["Bob", "Alice", "Carol", "Dave"];
+//! let recipients = derangement(players.len()).unwrap();
+//!
+//! for (giver, recipient) in recipients.iter().enumerate() {
+//!     assert_ne!(giver, *recipient); // Nobody buys a gift for themselves
+//!     println!("{} buys a gift for {}", players[giver], players[*recipient]);
+//! }
+//! ```
+
+use std::collections::BTreeSet;
+
+/// Returns a uniformly random permutation of `0..n`.
+pub fn permutation(n: usize) -> Vec<usize> {
+    let mut permutation = (0..n).collect::<Vec<_>>();
+    fastrand::shuffle(&mut permutation);
+    permutation
+}
+
+/// Returns a uniformly random derangement of `0..n`, a permutation where no index is mapped to itself.
+///
+/// Returns `None` when `n == 1`, as a single element can't be deranged.
+pub fn derangement(n: usize) -> Option<Vec<usize>> {
+    if n == 1 {
+        return None;
+    }
+
+    // Roughly 1 in e permutations is a derangement, so rejection is cheap and exactly uniform
+    loop {
+        let candidate = permutation(n);
+        if candidate.iter().enumerate().all(|(i, &p)| i != p) {
+            return Some(candidate);
+        }
+    }
+}
+
+/// Returns a uniformly random `k`-subset of `0..n` in ascending order.
+///
+/// Returns `None` when `k > n`.
+pub fn combination(n: usize, k: usize) -> Option<Vec<usize>> {
+    if k > n {
+        return None;
+    }
+
+    // Floyd's algorithm, O(k) draws regardless of n
+    let mut chosen = BTreeSet::new();
+    for j in (n - k)..n {
+        let t = fastrand::usize(0..=j);
+        if !chosen.insert(t) {
+            chosen.insert(j);
+        }
+    }
+
+    Some(chosen.into_iter().collect())
+}
+
+/// Returns a uniformly random partition of `0..n` into exactly `k` non-empty blocks.
+///
+/// Each block is in ascending order and blocks are ordered by their smallest element.
+/// Returns `None` when no such partition exists (`k > n`, or `k == 0` with `n > 0`).
+pub fn set_partition_into(n: usize, k: usize) -> Option<Vec<Vec<usize>>> {
+    if k > n || (k == 0 && n > 0) {
+        return None;
+    }
+
+    let table = ln_stirling_table(n, k);
+    Some(partition_from_table(&table, n, k))
+}
+
+/// Returns a uniformly random partition of `0..n` into any number of non-empty blocks.
+///
+/// Each block is in ascending order and blocks are ordered by their smallest element.
+pub fn set_partition(n: usize) -> Vec<Vec<usize>> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let table = ln_stirling_table(n, n);
+
+    // P(k blocks) = S(n, k) / B(n)
+    let max = table[n][1..=n]
+        .iter()
+        .cloned()
+        .fold(f64::NEG_INFINITY, f64::max);
+    let weights = table[n][1..=n]
+        .iter()
+        .map(|ln| (ln - max).exp())
+        .collect::<Vec<_>>();
+    let mut roll = fastrand::f64() * weights.iter().sum::<f64>();
+    let mut k = n;
+    for (i, weight) in weights.iter().enumerate() {
+        if roll < *weight {
+            k = i + 1;
+            break;
+        }
+        roll -= weight;
+    }
+
+    partition_from_table(&table, n, k)
+}
+
+/// `table[m][j] = ln S(m, j)`, the log of the Stirling numbers of the second kind.
+fn ln_stirling_table(n: usize, k: usize) -> Vec<Vec<f64>> {
+    let mut table = vec![vec![f64::NEG_INFINITY; k + 1]; n + 1];
+    table[0][0] = 0.0;
+    for m in 1..=n {
+        for j in 1..=k.min(m) {
+            // S(m, j) = j * S(m - 1, j) + S(m - 1, j - 1)
+            table[m][j] = ln_add((j as f64).ln() + table[m - 1][j], table[m - 1][j - 1]);
+        }
+    }
+    table
+}
+
+fn ln_add(a: f64, b: f64) -> f64 {
+    if a == f64::NEG_INFINITY {
+        return b;
+    }
+    if b == f64::NEG_INFINITY {
+        return a;
+    }
+    let max = a.max(b);
+    max + ((a - max).exp() + (b - max).exp()).ln()
+}
+
+fn partition_from_table(table: &[Vec<f64>], n: usize, k: usize) -> Vec<Vec<usize>> {
+    // Working down from the last element, each one either starts its own block
+    // (S(m - 1, j - 1) of the S(m, j) partitions) or joins one of the j blocks of the rest.
+    let mut starts_block = vec![false; n];
+    let mut j = k;
+    for m in (1..=n).rev() {
+        let p_alone = (table[m - 1][j - 1] - table[m][j]).exp();
+        if fastrand::f64() < p_alone {
+            starts_block[m - 1] = true;
+            j -= 1;
+        }
+    }
+
+    // Replay forwards, a joining element picks uniformly from the blocks that exist so far
+    let mut blocks: Vec<Vec<usize>> = Vec::with_capacity(k);
+    for (element, starts_block) in starts_block.into_iter().enumerate() {
+        if starts_block {
+            blocks.push(vec![element]);
+        } else {
+            let index = fastrand::usize(0..blocks.len());
+            blocks[index].push(element);
+        }
+    }
+    blocks
+}
@@ -0,0 +1,370 @@
+//! Dice
+//!
+//! Parses and rolls tabletop dice notation:
+//!
+//! - `3d6`, `d20`: roll dice and add them up, the count defaults to 1
+//! - `d%`: shorthand for `d100`
+//! - `4d6kh3`, `4d6k3`: keep the highest 3, `2d20kl1` keeps the lowest
+//! - `1d6!`: exploding, a die that rolls its highest face is rolled again and added
+//! - `+ - * /` and parentheses between terms, division rounds down
+//!
+//! ```rust
+//! use rantz_random::dice::DiceExpr;
+//!
+//! let fireball: DiceExpr = "8d6 + 2".parse().unwrap();
+//! let roll = fireball.roll();
+//!
+//! assert!((10..=50).contains(&roll.total));
+//! for group in &roll.groups {
+//!     println!("{}: {:?}", group.notation, group.dice);
+//! }
+//! ```
+//!
+//! [DiceExpr::probabilities] gives the exact odds of every total instead of rolling:
+//!
+//! ```rust
+//! use rantz_random::dice::DiceExpr;
+//!
+//! let check: DiceExpr = "1d20 + 5".parse().unwrap();
+//! let odds = check.probabilities().unwrap();
+//!
+//! assert!((odds.at_least(15) - 0.55).abs() < 1e-9);
+//! ```
+
+mod parser;
+mod probability;
+
+pub use probability::*;
+
+use crate::distributions::Distribution;
+use crate::random_traits::RandomRange;
+use std::fmt;
+use std::str::FromStr;
+
+/// The most dice a single term can roll.
+pub const MAX_DICE: u32 = 10_000;
+
+/// Exploding dice stop after this many extra rolls.
+pub const MAX_EXPLOSIONS: u32 = 100;
+
+/// How deeply brackets and negations can nest.
+pub const MAX_DEPTH: usize = 64;
+
+/// A parsed dice expression, roll it as many times as needed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiceExpr {
+    pub(crate) expr: Expr,
+}
+
+/// The result of rolling a [DiceExpr].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiceRoll {
+    pub total: i64,
+    /// One group per dice term, in the order they appear in the expression.
+    pub groups: Vec<DiceGroup>,
+}
+
+/// The dice rolled for a single term such as `4d6kh3`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiceGroup {
+    pub notation: String,
+    pub dice: Vec<DieRoll>,
+    /// The sum of the kept dice.
+    pub total: i64,
+}
+
+/// A single die, including any explosions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DieRoll {
+    /// Every face rolled, more than one when the die exploded.
+    pub faces: Vec<u32>,
+    /// The sum of `faces`.
+    pub value: i64,
+    /// `false` when dropped by a keep highest or keep lowest.
+    pub kept: bool,
+}
+
+/// Why a dice expression couldn't be parsed, positions are character offsets into the input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiceError {
+    UnexpectedCharacter {
+        character: char,
+        position: usize,
+    },
+    UnexpectedEnd,
+    NumberTooLarge {
+        position: usize,
+    },
+    /// Dice counts must be between 1 and [MAX_DICE].
+    InvalidCount {
+        position: usize,
+    },
+    /// Dice need at least one side.
+    InvalidSides {
+        position: usize,
+    },
+    /// Keeping needs at least one die and no more than were rolled.
+    InvalidKeep {
+        position: usize,
+    },
+    /// A `d1` would explode forever.
+    InvalidExplode {
+        position: usize,
+    },
+    /// Nested deeper than [MAX_DEPTH].
+    TooDeep {
+        position: usize,
+    },
+}
+
+impl fmt::Display for DiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiceError::UnexpectedCharacter {
+                character,
+                position,
+            } => write!(f, "unexpected '{}' at {}", character, position),
+            DiceError::UnexpectedEnd => write!(f, "unexpected end of expression"),
+            DiceError::NumberTooLarge { position } => {
+                write!(f, "number at {} is too large", position)
+            }
+            DiceError::InvalidCount { position } => write!(
+                f,
+                "dice count at {} must be between 1 and {}",
+                position, MAX_DICE
+            ),
+            DiceError::InvalidSides { position } => {
+                write!(f, "dice at {} need at least one side", position)
+            }
+            DiceError::InvalidKeep { position } => write!(
+                f,
+                "keep at {} must be between 1 and the number of dice",
+                position
+            ),
+            DiceError::InvalidExplode { position } => {
+                write!(f, "a d1 can't explode, at {}", position)
+            }
+            DiceError::TooDeep { position } => write!(
+                f,
+                "expression at {} nests deeper than {}",
+                position, MAX_DEPTH
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DiceError {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Expr {
+    Number(i64),
+    Dice(DiceTerm),
+    Neg(Box<Expr>),
+    Group(Box<Expr>),
+    /// Operators of the same precedence applied left to right, kept flat so long sums don't nest.
+    Chain(Box<Expr>, Vec<(Op, Expr)>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct DiceTerm {
+    pub(crate) count: u32,
+    pub(crate) sides: u32,
+    pub(crate) explode: bool,
+    pub(crate) keep: Option<Keep>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Keep {
+    Highest(u32),
+    Lowest(u32),
+}
+
+impl DiceExpr {
+    pub fn parse(input: &str) -> Result<Self, DiceError> {
+        parser::parse(input).map(|expr| Self { expr })
+    }
+
+    /// Rolls using the global generator.
+    pub fn roll(&self) -> DiceRoll {
+        self.roll_dice(&mut |sides| u32::random_range(1, sides))
+    }
+
+    /// Rolls using `rng`.
+    pub fn roll_with(&self, rng: &mut fastrand::Rng) -> DiceRoll {
+        self.roll_dice(&mut |sides| rng.u32(1..=sides))
+    }
+
+    /// The exact odds of every total, `None` if the expression has too many outcomes to analyse.
+    ///
+    /// The analysis is limited to [MAX_OUTCOMES] totals.
+    pub fn probabilities(&self) -> Option<DiceProbabilities> {
+        self.expr.probabilities()
+    }
+
+    fn roll_dice(&self, die: &mut dyn FnMut(u32) -> u32) -> DiceRoll {
+        let mut groups = Vec::new();
+        let total = self.expr.evaluate(die, &mut groups);
+        DiceRoll { total, groups }
+    }
+}
+
+impl FromStr for DiceExpr {
+    type Err = DiceError;
+
+    fn from_str(input: &str) -> Result<Self, DiceError> {
+        Self::parse(input)
+    }
+}
+
+impl fmt::Display for DiceExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.expr)
+    }
+}
+
+impl Distribution<i64> for DiceExpr {
+    fn sample(&self, rng: &mut fastrand::Rng) -> i64 {
+        self.roll_with(rng).total
+    }
+}
+
+impl Expr {
+    fn evaluate(&self, die: &mut dyn FnMut(u32) -> u32, groups: &mut Vec<DiceGroup>) -> i64 {
+        match self {
+            Expr::Number(number) => *number,
+            Expr::Dice(term) => {
+                let group = term.roll(die);
+                let total = group.total;
+                groups.push(group);
+                total
+            }
+            Expr::Neg(expr) => expr.evaluate(die, groups).saturating_neg(),
+            Expr::Group(expr) => expr.evaluate(die, groups),
+            Expr::Chain(first, rest) => {
+                let mut total = first.evaluate(die, groups);
+                for (op, expr) in rest {
+                    let rhs = expr.evaluate(die, groups);
+                    total = op.apply(total, rhs);
+                }
+                total
+            }
+        }
+    }
+}
+
+impl Op {
+    /// Saturates instead of overflowing, dividing by zero gives zero.
+    pub(crate) fn apply(self, lhs: i64, rhs: i64) -> i64 {
+        match self {
+            Op::Add => lhs.saturating_add(rhs),
+            Op::Sub => lhs.saturating_sub(rhs),
+            Op::Mul => lhs.saturating_mul(rhs),
+            Op::Div => {
+                if rhs == 0 {
+                    return 0;
+                }
+                let quotient = lhs.saturating_div(rhs);
+                if lhs.wrapping_rem(rhs) != 0 && (lhs < 0) != (rhs < 0) {
+                    quotient - 1
+                } else {
+                    quotient
+                }
+            }
+        }
+    }
+}
+
+impl DiceTerm {
+    fn roll(&self, die: &mut dyn FnMut(u32) -> u32) -> DiceGroup {
+        let mut dice = (0..self.count)
+            .map(|_| {
+                let mut faces = vec![die(self.sides)];
+                while self.explode
+                    && faces.last() == Some(&self.sides)
+                    && faces.len() <= MAX_EXPLOSIONS as usize
+                {
+                    faces.push(die(self.sides));
+                }
+                let value = faces.iter().map(|face| *face as i64).sum();
+                DieRoll {
+                    faces,
+                    value,
+                    kept: true,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(keep) = self.keep {
+            let mut order = (0..dice.len()).collect::<Vec<_>>();
+            let kept = match keep {
+                Keep::Highest(kept) => {
+                    order.sort_by_key(|i| std::cmp::Reverse(dice[*i].value));
+                    kept
+                }
+                Keep::Lowest(kept) => {
+                    order.sort_by_key(|i| dice[*i].value);
+                    kept
+                }
+            };
+            for i in &order[kept as usize..] {
+                dice[*i].kept = false;
+            }
+        }
+
+        DiceGroup {
+            notation: self.to_string(),
+            total: dice
+                .iter()
+                .filter(|die| die.kept)
+                .map(|die| die.value)
+                .sum(),
+            dice,
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Number(number) => write!(f, "{}", number),
+            Expr::Dice(term) => write!(f, "{}", term),
+            Expr::Neg(expr) => write!(f, "-{}", expr),
+            Expr::Group(expr) => write!(f, "({})", expr),
+            Expr::Chain(first, rest) => {
+                write!(f, "{}", first)?;
+                for (op, expr) in rest {
+                    let op = match op {
+                        Op::Add => "+",
+                        Op::Sub => "-",
+                        Op::Mul => "*",
+                        Op::Div => "/",
+                    };
+                    write!(f, " {} {}", op, expr)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Display for DiceTerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}d{}", self.count, self.sides)?;
+        if self.explode {
+            write!(f, "!")?;
+        }
+        match self.keep {
+            Some(Keep::Highest(kept)) => write!(f, "kh{}", kept),
+            Some(Keep::Lowest(kept)) => write!(f, "kl{}", kept),
+            None => Ok(()),
+        }
+    }
+}
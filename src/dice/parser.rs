@@ -0,0 +1,239 @@
+use super::{DiceError, DiceTerm, Expr, Keep, Op, MAX_DEPTH, MAX_DICE};
+
+/// Recursive descent over
+///
+/// ```text
+/// expr  = term (("+" | "-") term)*
+/// term  = unary (("*" | "/") unary)*
+/// unary = "-" unary | atom
+/// atom  = number | dice | "(" expr ")"
+/// dice  = number? "d" (number | "%") "!"? (("kh" | "kl" | "k") number)?
+/// ```
+///
+/// Whitespace is allowed between any two tokens. Runs of `+ -` or `* /` are kept flat,
+/// and brackets or negations nested deeper than [MAX_DEPTH] are rejected,
+/// so neither parsing nor rolling can overflow the stack.
+pub(crate) fn parse(input: &str) -> Result<Expr, DiceError> {
+    let mut parser = Parser {
+        chars: input.chars().collect(),
+        position: 0,
+        nesting: 0,
+    };
+    let expr = parser.expr()?;
+    match parser.peek() {
+        None => Ok(expr),
+        Some(character) => Err(parser.unexpected(character)),
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+    /// Brackets and negations the parser is currently inside.
+    nesting: usize,
+}
+
+impl Parser {
+    fn peek(&mut self) -> Option<char> {
+        while self
+            .chars
+            .get(self.position)
+            .is_some_and(|c| c.is_whitespace())
+        {
+            self.position += 1;
+        }
+        self.chars.get(self.position).copied()
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self
+            .peek()
+            .is_some_and(|c| c.eq_ignore_ascii_case(&expected))
+        {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn unexpected(&self, character: char) -> DiceError {
+        DiceError::UnexpectedCharacter {
+            character,
+            position: self.position,
+        }
+    }
+
+    /// Parses with `parse` inside one more bracket or negation, rejecting anything past [MAX_DEPTH].
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<Expr, DiceError>,
+    ) -> Result<Expr, DiceError> {
+        if self.nesting >= MAX_DEPTH {
+            return Err(DiceError::TooDeep {
+                position: self.position,
+            });
+        }
+        self.nesting += 1;
+        let expr = parse(self);
+        self.nesting -= 1;
+        expr
+    }
+
+    fn expr(&mut self) -> Result<Expr, DiceError> {
+        let first = self.term()?;
+        let mut rest = Vec::new();
+        loop {
+            let op = if self.eat('+') {
+                Op::Add
+            } else if self.eat('-') {
+                Op::Sub
+            } else {
+                return Ok(chain(first, rest));
+            };
+            rest.push((op, self.term()?));
+        }
+    }
+
+    fn term(&mut self) -> Result<Expr, DiceError> {
+        let first = self.unary()?;
+        let mut rest = Vec::new();
+        loop {
+            let op = if self.eat('*') {
+                Op::Mul
+            } else if self.eat('/') {
+                Op::Div
+            } else {
+                return Ok(chain(first, rest));
+            };
+            rest.push((op, self.unary()?));
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr, DiceError> {
+        if self.eat('-') {
+            return Ok(Expr::Neg(Box::new(self.nested(Self::unary)?)));
+        }
+        self.atom()
+    }
+
+    fn atom(&mut self) -> Result<Expr, DiceError> {
+        match self.peek() {
+            None => Err(DiceError::UnexpectedEnd),
+            Some('(') => {
+                self.position += 1;
+                let expr = self.nested(Self::expr)?;
+                if !self.eat(')') {
+                    return Err(match self.peek() {
+                        Some(character) => self.unexpected(character),
+                        None => DiceError::UnexpectedEnd,
+                    });
+                }
+                Ok(Expr::Group(Box::new(expr)))
+            }
+            Some('d' | 'D') => self.dice(1),
+            Some(c) if c.is_ascii_digit() => {
+                let start = self.position;
+                let number = self.number()?;
+                if self.peek().is_some_and(|c| c.eq_ignore_ascii_case(&'d')) {
+                    let count = u32::try_from(number)
+                        .ok()
+                        .filter(|count| (1..=MAX_DICE).contains(count))
+                        .ok_or(DiceError::InvalidCount { position: start })?;
+                    self.dice(count)
+                } else {
+                    i64::try_from(number)
+                        .map(Expr::Number)
+                        .map_err(|_| DiceError::NumberTooLarge { position: start })
+                }
+            }
+            Some(character) => Err(self.unexpected(character)),
+        }
+    }
+
+    fn number(&mut self) -> Result<u64, DiceError> {
+        self.peek();
+        let start = self.position;
+        let mut number: u64 = 0;
+        let mut digits = 0;
+        while let Some(digit) = self.chars.get(self.position).and_then(|c| c.to_digit(10)) {
+            number = number
+                .checked_mul(10)
+                .and_then(|number| number.checked_add(digit as u64))
+                .ok_or(DiceError::NumberTooLarge { position: start })?;
+            self.position += 1;
+            digits += 1;
+        }
+        if digits == 0 {
+            return Err(match self.peek() {
+                Some(character) => self.unexpected(character),
+                None => DiceError::UnexpectedEnd,
+            });
+        }
+        Ok(number)
+    }
+
+    fn dice(&mut self, count: u32) -> Result<Expr, DiceError> {
+        self.eat('d');
+
+        let sides_position = self.position;
+        let sides = if self.eat('%') {
+            100
+        } else {
+            u32::try_from(self.number()?)
+                .ok()
+                .filter(|sides| *sides > 0)
+                .ok_or(DiceError::InvalidSides {
+                    position: sides_position,
+                })?
+        };
+
+        let explode_position = self.position;
+        let explode = self.eat('!');
+        if explode && sides == 1 {
+            return Err(DiceError::InvalidExplode {
+                position: explode_position,
+            });
+        }
+
+        let keep_position = self.position;
+        let keep = if self.eat('k') {
+            let lowest = if self.eat('l') {
+                true
+            } else {
+                self.eat('h');
+                false
+            };
+            let number = self.number()?;
+            let keep = u32::try_from(number)
+                .ok()
+                .filter(|keep| (1..=count).contains(keep))
+                .ok_or(DiceError::InvalidKeep {
+                    position: keep_position,
+                })?;
+            Some(if lowest {
+                Keep::Lowest(keep)
+            } else {
+                Keep::Highest(keep)
+            })
+        } else {
+            None
+        };
+
+        Ok(Expr::Dice(DiceTerm {
+            count,
+            sides,
+            explode,
+            keep,
+        }))
+    }
+}
+
+/// A lone operand stays as it is rather than becoming a chain of one.
+fn chain(first: Expr, rest: Vec<(Op, Expr)>) -> Expr {
+    if rest.is_empty() {
+        first
+    } else {
+        Expr::Chain(Box::new(first), rest)
+    }
+}
@@ -0,0 +1,364 @@
+use super::{DiceTerm, Expr, Keep, Op, MAX_EXPLOSIONS};
+use crate::weighted_table::WeightedTable;
+
+/// The most distinct totals an analysis can produce.
+pub const MAX_OUTCOMES: usize = 1_000_000;
+
+/// Analyses that would take more steps than this give up.
+const MAX_WORK: u64 = 100_000_000;
+
+/// The exact probability of every total a [DiceExpr](super::DiceExpr) can roll.
+///
+/// ```rust
+/// use rantz_random::dice::DiceExpr;
+///
+/// let stat: DiceExpr = "4d6kh3".parse().unwrap();
+/// let odds = stat.probabilities().unwrap();
+///
+/// assert_eq!(odds.min(), 3);
+/// assert_eq!(odds.max(), 18);
+/// println!("mean {:.2}, 15 or better {:.1}%", odds.mean(), odds.at_least(15) * 100.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiceProbabilities {
+    min: i64,
+    probabilities: Vec<f64>,
+}
+
+impl DiceProbabilities {
+    /// The lowest total with a non-zero probability.
+    pub fn min(&self) -> i64 {
+        self.min
+    }
+
+    /// The highest total with a non-zero probability.
+    pub fn max(&self) -> i64 {
+        self.min + self.probabilities.len() as i64 - 1
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.iter()
+            .map(|(total, probability)| total as f64 * probability)
+            .sum()
+    }
+
+    pub fn variance(&self) -> f64 {
+        let mean = self.mean();
+        self.iter()
+            .map(|(total, probability)| (total as f64 - mean).powi(2) * probability)
+            .sum()
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// P(X = `total`)
+    pub fn probability(&self, total: i64) -> f64 {
+        total
+            .checked_sub(self.min)
+            .and_then(|index| usize::try_from(index).ok())
+            .and_then(|index| self.probabilities.get(index))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// P(X >= `total`)
+    pub fn at_least(&self, total: i64) -> f64 {
+        self.iter()
+            .filter(|(value, _)| *value >= total)
+            .map(|(_, probability)| probability)
+            .sum()
+    }
+
+    /// P(X <= `total`)
+    pub fn at_most(&self, total: i64) -> f64 {
+        self.iter()
+            .filter(|(value, _)| *value <= total)
+            .map(|(_, probability)| probability)
+            .sum()
+    }
+
+    /// Every total from [min](Self::min) to [max](Self::max) with its probability.
+    pub fn iter(&self) -> impl Iterator<Item = (i64, f64)> + '_ {
+        self.probabilities
+            .iter()
+            .enumerate()
+            .map(|(i, probability)| (self.min + i as i64, *probability))
+    }
+
+    /// A table weighting each total by its probability, for cheap repeated sampling.
+    ///
+    /// Weights are scaled to fill a `u32`, so totals rarer than roughly one in four billion are left out.
+    pub fn to_weighted_table(&self) -> WeightedTable<i64> {
+        let scale = u32::MAX as f64 - self.probabilities.len() as f64;
+        let mut table = WeightedTable::new();
+        for (total, probability) in self.iter() {
+            let weight = (probability * scale).round() as u32;
+            if weight > 0 {
+                table.values.push(total);
+                table.weights.push(weight);
+                table.total_weight += weight;
+            }
+        }
+        table
+    }
+
+    fn constant(value: i64) -> Self {
+        Self {
+            min: value,
+            probabilities: vec![1.0],
+        }
+    }
+
+    /// Drops zero probability totals from both ends.
+    fn trimmed(mut self) -> Self {
+        let leading = self
+            .probabilities
+            .iter()
+            .take_while(|probability| **probability == 0.0)
+            .count();
+        let trailing = self.probabilities[leading..]
+            .iter()
+            .rev()
+            .take_while(|probability| **probability == 0.0)
+            .count();
+        self.probabilities
+            .truncate(self.probabilities.len() - trailing);
+        self.probabilities.drain(..leading);
+        self.min += leading as i64;
+        self
+    }
+
+    fn negated(mut self) -> Option<Self> {
+        self.min = self.max().checked_neg()?;
+        self.probabilities.reverse();
+        Some(self)
+    }
+
+    fn add(&self, other: &Self) -> Option<Self> {
+        let outcomes = self.probabilities.len() + other.probabilities.len() - 1;
+        let work = self.probabilities.len() as u64 * other.probabilities.len() as u64;
+        if outcomes > MAX_OUTCOMES || work > MAX_WORK {
+            return None;
+        }
+
+        let mut probabilities = vec![0.0; outcomes];
+        for (i, a) in self.probabilities.iter().enumerate() {
+            for (j, b) in other.probabilities.iter().enumerate() {
+                probabilities[i + j] += a * b;
+            }
+        }
+        Some(Self {
+            min: self.min.checked_add(other.min)?,
+            probabilities,
+        })
+    }
+
+    /// The distribution of `count` independent copies added together.
+    fn repeated(&self, count: u32) -> Option<Self> {
+        let mut result = Self::constant(0);
+        let mut power = self.clone();
+        let mut count = count;
+        while count > 0 {
+            if count & 1 == 1 {
+                result = result.add(&power)?;
+            }
+            count >>= 1;
+            if count > 0 {
+                power = power.add(&power)?;
+            }
+        }
+        Some(result)
+    }
+
+    /// Combines every pair of totals with `op`, for operations that aren't a plain sum.
+    fn combine(&self, other: &Self, op: Op) -> Option<Self> {
+        let work = self.probabilities.len() as u64 * other.probabilities.len() as u64;
+        if work > MAX_WORK {
+            return None;
+        }
+
+        let pairs = || {
+            self.iter()
+                .flat_map(move |(a, p)| other.iter().map(move |(b, q)| (op.apply(a, b), p * q)))
+        };
+        let (min, max) = pairs().fold((i64::MAX, i64::MIN), |(min, max), (total, _)| {
+            (min.min(total), max.max(total))
+        });
+        let outcomes = usize::try_from(max.abs_diff(min)).ok()?.checked_add(1)?;
+        if outcomes > MAX_OUTCOMES {
+            return None;
+        }
+
+        let mut probabilities = vec![0.0; outcomes];
+        for (total, probability) in pairs() {
+            probabilities[total.abs_diff(min) as usize] += probability;
+        }
+        Some(Self { min, probabilities }.trimmed())
+    }
+}
+
+impl Expr {
+    pub(crate) fn probabilities(&self) -> Option<DiceProbabilities> {
+        match self {
+            Expr::Number(number) => Some(DiceProbabilities::constant(*number)),
+            Expr::Dice(term) => term.probabilities(),
+            Expr::Neg(expr) => expr.probabilities()?.negated(),
+            Expr::Group(expr) => expr.probabilities(),
+            Expr::Chain(first, rest) => {
+                let mut total = first.probabilities()?;
+                for (op, expr) in rest {
+                    let rhs = expr.probabilities()?;
+                    total = match op {
+                        Op::Add => total.add(&rhs),
+                        Op::Sub => total.add(&rhs.negated()?),
+                        Op::Mul | Op::Div => total.combine(&rhs, *op),
+                    }?;
+                }
+                Some(total)
+            }
+        }
+    }
+}
+
+impl DiceTerm {
+    fn probabilities(&self) -> Option<DiceProbabilities> {
+        let die = self.die()?;
+        match self.keep {
+            None => die.repeated(self.count),
+            Some(keep) => keep_probabilities(&die, self.count, keep),
+        }
+    }
+
+    /// The distribution of a single die, explosions included.
+    fn die(&self) -> Option<DiceProbabilities> {
+        let sides = self.sides as usize;
+        let face = 1.0 / self.sides as f64;
+        if !self.explode {
+            if sides > MAX_OUTCOMES {
+                return None;
+            }
+            return Some(DiceProbabilities {
+                min: 1,
+                probabilities: vec![face; sides],
+            });
+        }
+
+        // `j` explosions then a face below the highest, or every face the highest
+        let most = MAX_EXPLOSIONS as usize + 1;
+        if sides.checked_mul(most)? > MAX_OUTCOMES {
+            return None;
+        }
+        let mut probabilities = vec![0.0; sides * most];
+        let mut exploded = 1.0;
+        for j in 0..most {
+            for face_value in 1..sides {
+                probabilities[j * sides + face_value - 1] = exploded * face;
+            }
+            exploded *= face;
+        }
+        probabilities[sides * most - 1] = exploded;
+        Some(
+            DiceProbabilities {
+                min: 1,
+                probabilities,
+            }
+            .trimmed(),
+        )
+    }
+}
+
+/// The sum of the `keep` highest or lowest of `count` dice.
+///
+/// Walks the faces from the best kept value down, tracking how many dice have landed on the faces seen so far.
+/// Given that a die hasn't landed on an earlier face, it lands on this one with probability `p(face) / P(rest)`,
+/// so the number that do is binomial.
+fn keep_probabilities(
+    die: &DiceProbabilities,
+    count: u32,
+    keep: Keep,
+) -> Option<DiceProbabilities> {
+    let (kept, highest) = match keep {
+        Keep::Highest(kept) => (kept as usize, true),
+        Keep::Lowest(kept) => (kept as usize, false),
+    };
+    let count = count as usize;
+    let faces = die.probabilities.len();
+    let outcomes = kept.checked_mul(usize::try_from(die.max()).ok()?)? + 1;
+    let work = (faces as u64)
+        .saturating_mul(kept as u64)
+        .saturating_mul(kept as u64)
+        .saturating_mul(outcomes as u64);
+    if outcomes > MAX_OUTCOMES || work > MAX_WORK {
+        return None;
+    }
+
+    let ln_factorials = (0..=count)
+        .scan(0.0, |ln, n| {
+            if n > 0 {
+                *ln += (n as f64).ln();
+            }
+            Some(*ln)
+        })
+        .collect::<Vec<_>>();
+    let binomial = |n: usize, k: usize, p: f64| -> f64 {
+        if p >= 1.0 {
+            return if k == n { 1.0 } else { 0.0 };
+        }
+        if p <= 0.0 {
+            return if k == 0 { 1.0 } else { 0.0 };
+        }
+        (ln_factorials[n] - ln_factorials[k] - ln_factorials[n - k]
+            + k as f64 * p.ln()
+            + (n - k) as f64 * (1.0 - p).ln())
+        .exp()
+    };
+
+    let mut order = die.iter().collect::<Vec<_>>();
+    if highest {
+        order.reverse();
+    }
+
+    // states[m][sum], `m` dice have landed on faces already seen, keeping `sum`
+    let mut states = vec![vec![0.0; outcomes]; kept];
+    states[0][0] = 1.0;
+    let mut result = vec![0.0; outcomes];
+    let mut rest = 1.0;
+    for (value, probability) in order {
+        if probability == 0.0 {
+            continue;
+        }
+        let value = value as usize;
+        let p = (probability / rest).min(1.0);
+        rest -= probability;
+
+        let mut next = vec![vec![0.0; outcomes]; kept];
+        for (m, sums) in states.iter().enumerate() {
+            let remaining = count - m;
+            let needed = kept - m;
+            for (sum, mass) in sums.iter().enumerate() {
+                if *mass == 0.0 {
+                    continue;
+                }
+                let mut below_needed = 0.0;
+                for landed in 0..needed {
+                    let chance = binomial(remaining, landed, p);
+                    below_needed += chance;
+                    next[m + landed][sum + value * landed] += mass * chance;
+                }
+                // Enough dice landed here to fill the kept ones, the rest don't matter
+                result[sum + value * needed] += mass * (1.0 - below_needed).max(0.0);
+            }
+        }
+        states = next;
+    }
+
+    Some(
+        DiceProbabilities {
+            min: 0,
+            probabilities: result,
+        }
+        .trimmed(),
+    )
+}
@@ -0,0 +1,254 @@
+use crate::distributions::global_rng;
+use crate::WeightedTable;
+use rantz_spatial2d::prelude::*;
+use std::marker::PhantomData;
+
+/// Weight for the point closest to the heading given to [DirectionTable::towards].
+const TOWARDS_WEIGHT: f64 = 1000.0;
+
+/// DirectionTable
+///
+/// Weights for every point of a compass, for directions that aren't all equally likely,
+/// like a prevailing wind or an AI that prefers to keep going the way it's facing:
+///
+/// ```rust
+/// use rantz_random::DirectionTable;
+/// use rantz_spatial2d::prelude::*;
+///
+/// // Mostly blows from the west, occasionally from the north
+/// let wind = DirectionTable::<CompassRose>::new()
+///     .with_weight(CompassRose::W, 6)
+///     .with_weight(CompassRose::NW, 3)
+///     .with_weight(CompassRose::N, 1);
+/// let gust = wind.random().unwrap();
+///
+/// // Clusters around north east, the higher the concentration the tighter
+/// let wander = DirectionTable::<CompassHalfwinds>::towards(Degrees::new(45.0), 2.0);
+/// ```
+///
+/// Headings are counterclockwise from east, matching [Degrees] and [Radians].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DirectionTable<C: CompassPoint> {
+    weights: Vec<u32>,
+    marker: PhantomData<C>,
+}
+
+impl<C: CompassPoint> Default for DirectionTable<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: CompassPoint> DirectionTable<C> {
+    /// Every point has a weight of `0`.
+    pub fn new() -> Self {
+        Self {
+            weights: vec![0; C::POINTS.len()],
+            marker: PhantomData,
+        }
+    }
+
+    /// Every point has a weight of `1`.
+    pub fn uniform() -> Self {
+        Self {
+            weights: vec![1; C::POINTS.len()],
+            marker: PhantomData,
+        }
+    }
+
+    /// Weights following a von Mises distribution around `heading`.
+    ///
+    /// A `concentration` of `0.0` weights every point equally,
+    /// higher values favour points closer to `heading` more and more strongly.
+    /// Negative and NaN concentrations are treated as `0.0`, infinite ones as [f32::MAX].
+    /// The point closest to `heading` always gets the largest weight, so the table is never empty.
+    pub fn towards(heading: impl Into<Degrees>, concentration: f32) -> Self {
+        let closeness = closeness::<C>(heading.into());
+        let closest = closeness.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        Self {
+            weights: closeness
+                .iter()
+                .map(|&cos| {
+                    (TOWARDS_WEIGHT * von_mises_factor(cos, closest, concentration)).round() as u32
+                })
+                .collect(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Scales every weight by a von Mises factor around `heading`.
+    ///
+    /// The factor is `1.0` for the weighted point closest to `heading` and falls off away from it,
+    /// so that point keeps its weight and a table that wasn't empty never becomes empty.
+    /// Combines a bias with existing weights, like an AI that prefers some directions and is drawn towards a target.
+    pub fn biased_towards(mut self, heading: impl Into<Degrees>, concentration: f32) -> Self {
+        let closeness = closeness::<C>(heading.into());
+        let closest = closeness
+            .iter()
+            .zip(&self.weights)
+            .filter(|(_, &weight)| weight > 0)
+            .map(|(&cos, _)| cos)
+            .fold(f64::NEG_INFINITY, f64::max);
+        for (weight, &cos) in self.weights.iter_mut().zip(&closeness) {
+            *weight =
+                (*weight as f64 * von_mises_factor(cos, closest, concentration)).round() as u32;
+        }
+        self
+    }
+
+    pub fn with_weight(mut self, point: C, weight: u32) -> Self {
+        self.set_weight(point, weight);
+        self
+    }
+
+    pub fn set_weight(&mut self, point: C, weight: u32) {
+        self.weights[index_of(point)] = weight;
+    }
+
+    pub fn weight(&self, point: C) -> u32 {
+        self.weights[index_of(point)]
+    }
+
+    /// The sum of every weight, as a `u64` so it can't overflow.
+    pub fn total_weight(&self) -> u64 {
+        self.weights.iter().map(|&weight| weight as u64).sum()
+    }
+
+    /// Every point with its weight, in order around the compass.
+    pub fn iter(&self) -> impl Iterator<Item = (C, u32)> + '_ {
+        C::POINTS
+            .iter()
+            .zip(&self.weights)
+            .map(|(&(point, _), &weight)| (point, weight))
+    }
+
+    /// A point picked in proportion to its weight, or `None` if every weight is `0`.
+    pub fn random(&self) -> Option<C> {
+        self.random_with(&mut global_rng())
+    }
+
+    /// [random](Self::random) using `rng`.
+    pub fn random_with(&self, rng: &mut fastrand::Rng) -> Option<C> {
+        let total = self.total_weight();
+        if total == 0 {
+            return None;
+        }
+        let mut roll = rng.u64(0..total);
+        for (point, weight) in self.iter() {
+            if roll < weight as u64 {
+                return Some(point);
+            }
+            roll -= weight as u64;
+        }
+        None
+    }
+}
+
+impl<C: CompassPoint> From<DirectionTable<C>> for WeightedTable<C> {
+    fn from(table: DirectionTable<C>) -> Self {
+        table.iter().collect()
+    }
+}
+
+impl<C: CompassPoint> From<&WeightedTable<C>> for DirectionTable<C> {
+    /// Points missing from `table` get a weight of `0`.
+    fn from(table: &WeightedTable<C>) -> Self {
+        let mut directions = Self::new();
+        for (point, weight) in table.iter() {
+            let total = &mut directions.weights[index_of(*point)];
+            *total = total.saturating_add(*weight);
+        }
+        directions
+    }
+}
+
+impl<C: CompassPoint> From<WeightedTable<C>> for DirectionTable<C> {
+    fn from(table: WeightedTable<C>) -> Self {
+        Self::from(&table)
+    }
+}
+
+/// `cos(point - heading)` for every point, higher is closer.
+fn closeness<C: CompassPoint>(heading: Degrees) -> Vec<f64> {
+    let heading = heading.to_f32() as f64;
+    C::POINTS
+        .iter()
+        .map(|&(_, degrees)| (degrees as f64 - heading).to_radians().cos())
+        .collect()
+}
+
+/// `exp(concentration * (cos - closest))`, the von Mises density relative to the closest point's,
+/// so it's exactly `1.0` there however large the concentration.
+fn von_mises_factor(cos: f64, closest: f64, concentration: f32) -> f64 {
+    let concentration = if concentration.is_nan() {
+        0.0
+    } else {
+        concentration.clamp(0.0, f32::MAX) as f64
+    };
+    (concentration * (cos - closest)).exp().min(1.0)
+}
+
+fn index_of<C: CompassPoint>(point: C) -> usize {
+    C::POINTS
+        .iter()
+        .position(|&(p, _)| p == point)
+        .expect("every compass point is listed")
+}
+
+/// Compass types a [DirectionTable] can hold.
+pub trait CompassPoint: Copy + PartialEq + 'static + private::Sealed {
+    /// Every point with its heading in degrees, counterclockwise from east.
+    const POINTS: &'static [(Self, f32)];
+}
+
+impl CompassPoint for Compass {
+    const POINTS: &'static [(Compass, f32)] = &[
+        (Compass::E, 0.0),
+        (Compass::N, 90.0),
+        (Compass::W, 180.0),
+        (Compass::S, 270.0),
+    ];
+}
+
+impl CompassPoint for CompassRose {
+    const POINTS: &'static [(CompassRose, f32)] = &[
+        (CompassRose::E, 0.0),
+        (CompassRose::NE, 45.0),
+        (CompassRose::N, 90.0),
+        (CompassRose::NW, 135.0),
+        (CompassRose::W, 180.0),
+        (CompassRose::SW, 225.0),
+        (CompassRose::S, 270.0),
+        (CompassRose::SE, 315.0),
+    ];
+}
+
+impl CompassPoint for CompassHalfwinds {
+    const POINTS: &'static [(CompassHalfwinds, f32)] = &[
+        (CompassHalfwinds::E, 0.0),
+        (CompassHalfwinds::ENE, 22.5),
+        (CompassHalfwinds::NE, 45.0),
+        (CompassHalfwinds::NNE, 67.5),
+        (CompassHalfwinds::N, 90.0),
+        (CompassHalfwinds::NNW, 112.5),
+        (CompassHalfwinds::NW, 135.0),
+        (CompassHalfwinds::WNW, 157.5),
+        (CompassHalfwinds::W, 180.0),
+        (CompassHalfwinds::WSW, 202.5),
+        (CompassHalfwinds::SW, 225.0),
+        (CompassHalfwinds::SSW, 247.5),
+        (CompassHalfwinds::S, 270.0),
+        (CompassHalfwinds::SSE, 292.5),
+        (CompassHalfwinds::SE, 315.0),
+        (CompassHalfwinds::ESE, 337.5),
+    ];
+}
+
+mod private {
+    use rantz_spatial2d::prelude::*;
+
+    pub trait Sealed {}
+    impl Sealed for Compass {}
+    impl Sealed for CompassRose {}
+    impl Sealed for CompassHalfwinds {}
+}
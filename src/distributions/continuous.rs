@@ -0,0 +1,399 @@
+use super::{
+    open_unit, standard_exponential, standard_gamma, standard_normal, Distribution, Float,
+};
+use std::f64::consts::{PI, TAU};
+use std::marker::PhantomData;
+
+macro_rules! impl_sample {
+    ($($t:ident),*) => {
+        $(
+            impl<F: Float> Distribution<F> for $t<F> {
+                fn sample(&self, rng: &mut fastrand::Rng) -> F {
+                    F::from_f64(self.sample_f64(rng))
+                }
+            }
+        )*
+    };
+}
+
+impl_sample!(
+    Normal,
+    LogNormal,
+    Exponential,
+    Gamma,
+    Beta,
+    Triangular,
+    Cauchy,
+    Weibull,
+    Pareto,
+    VonMises,
+    WrappedNormal
+);
+
+/// Normal (Gaussian) distribution with the given `mean` and `std_dev`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Normal<F = f64> {
+    mean: f64,
+    std_dev: f64,
+    marker: PhantomData<F>,
+}
+
+impl<F: Float> Normal<F> {
+    /// `std_dev` must be finite and non-negative.
+    pub fn new(mean: F, std_dev: F) -> Option<Self> {
+        let (mean, std_dev) = (mean.to_f64(), std_dev.to_f64());
+        if !mean.is_finite() || !std_dev.is_finite() || std_dev < 0.0 {
+            return None;
+        }
+        Some(Self {
+            mean,
+            std_dev,
+            marker: PhantomData,
+        })
+    }
+
+    fn sample_f64(&self, rng: &mut fastrand::Rng) -> f64 {
+        self.mean + self.std_dev * standard_normal(rng)
+    }
+}
+
+/// Log-normal distribution, `exp(X)` where `X` is normal with mean `mu` and standard deviation `sigma`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LogNormal<F = f64> {
+    mu: f64,
+    sigma: f64,
+    marker: PhantomData<F>,
+}
+
+impl<F: Float> LogNormal<F> {
+    /// `sigma` must be finite and non-negative.
+    pub fn new(mu: F, sigma: F) -> Option<Self> {
+        let (mu, sigma) = (mu.to_f64(), sigma.to_f64());
+        if !mu.is_finite() || !sigma.is_finite() || sigma < 0.0 {
+            return None;
+        }
+        Some(Self {
+            mu,
+            sigma,
+            marker: PhantomData,
+        })
+    }
+
+    fn sample_f64(&self, rng: &mut fastrand::Rng) -> f64 {
+        (self.mu + self.sigma * standard_normal(rng)).exp()
+    }
+}
+
+/// Exponential distribution with rate `lambda`, the mean is `1 / lambda`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Exponential<F = f64> {
+    lambda: f64,
+    marker: PhantomData<F>,
+}
+
+impl<F: Float> Exponential<F> {
+    /// `lambda` must be finite and positive.
+    pub fn new(lambda: F) -> Option<Self> {
+        let lambda = lambda.to_f64();
+        if !lambda.is_finite() || lambda <= 0.0 {
+            return None;
+        }
+        Some(Self {
+            lambda,
+            marker: PhantomData,
+        })
+    }
+
+    fn sample_f64(&self, rng: &mut fastrand::Rng) -> f64 {
+        standard_exponential(rng) / self.lambda
+    }
+}
+
+/// Gamma distribution with the given `shape` (k) and `scale` (θ), the mean is `shape * scale`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gamma<F = f64> {
+    shape: f64,
+    scale: f64,
+    marker: PhantomData<F>,
+}
+
+impl<F: Float> Gamma<F> {
+    /// `shape` and `scale` must be finite and positive.
+    pub fn new(shape: F, scale: F) -> Option<Self> {
+        let (shape, scale) = (shape.to_f64(), scale.to_f64());
+        if !shape.is_finite() || !scale.is_finite() || shape <= 0.0 || scale <= 0.0 {
+            return None;
+        }
+        Some(Self {
+            shape,
+            scale,
+            marker: PhantomData,
+        })
+    }
+
+    fn sample_f64(&self, rng: &mut fastrand::Rng) -> f64 {
+        standard_gamma(self.shape, rng) * self.scale
+    }
+}
+
+/// Beta distribution on `0.0..=1.0` with shape parameters `alpha` and `beta`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Beta<F = f64> {
+    alpha: f64,
+    beta: f64,
+    marker: PhantomData<F>,
+}
+
+impl<F: Float> Beta<F> {
+    /// `alpha` and `beta` must be finite and positive.
+    pub fn new(alpha: F, beta: F) -> Option<Self> {
+        let (alpha, beta) = (alpha.to_f64(), beta.to_f64());
+        if !alpha.is_finite() || !beta.is_finite() || alpha <= 0.0 || beta <= 0.0 {
+            return None;
+        }
+        Some(Self {
+            alpha,
+            beta,
+            marker: PhantomData,
+        })
+    }
+
+    fn sample_f64(&self, rng: &mut fastrand::Rng) -> f64 {
+        let x = standard_gamma(self.alpha, rng);
+        let y = standard_gamma(self.beta, rng);
+        if x + y == 0.0 {
+            // Both underflowed, only possible with tiny shapes where the mass sits at the ends
+            return if rng.f64() * (self.alpha + self.beta) < self.alpha {
+                1.0
+            } else {
+                0.0
+            };
+        }
+        x / (x + y)
+    }
+}
+
+/// Triangular distribution between `min` and `max`, peaking at `mode`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Triangular<F = f64> {
+    min: f64,
+    max: f64,
+    mode: f64,
+    marker: PhantomData<F>,
+}
+
+impl<F: Float> Triangular<F> {
+    /// All parameters must be finite with `min <= mode <= max` and `min < max`.
+    pub fn new(min: F, max: F, mode: F) -> Option<Self> {
+        let (min, max, mode) = (min.to_f64(), max.to_f64(), mode.to_f64());
+        if !min.is_finite() || !max.is_finite() || !mode.is_finite() {
+            return None;
+        }
+        if min >= max || mode < min || mode > max {
+            return None;
+        }
+        Some(Self {
+            min,
+            max,
+            mode,
+            marker: PhantomData,
+        })
+    }
+
+    fn sample_f64(&self, rng: &mut fastrand::Rng) -> f64 {
+        // Inverse CDF
+        let u = rng.f64();
+        let range = self.max - self.min;
+        let split = (self.mode - self.min) / range;
+        if u < split {
+            self.min + (u * range * (self.mode - self.min)).sqrt()
+        } else {
+            self.max - ((1.0 - u) * range * (self.max - self.mode)).sqrt()
+        }
+    }
+}
+
+/// Cauchy distribution centered on `median` with half-width `scale`.
+///
+/// Heavy tailed, it has no mean or variance.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cauchy<F = f64> {
+    median: f64,
+    scale: f64,
+    marker: PhantomData<F>,
+}
+
+impl<F: Float> Cauchy<F> {
+    /// `median` must be finite and `scale` finite and positive.
+    pub fn new(median: F, scale: F) -> Option<Self> {
+        let (median, scale) = (median.to_f64(), scale.to_f64());
+        if !median.is_finite() || !scale.is_finite() || scale <= 0.0 {
+            return None;
+        }
+        Some(Self {
+            median,
+            scale,
+            marker: PhantomData,
+        })
+    }
+
+    fn sample_f64(&self, rng: &mut fastrand::Rng) -> f64 {
+        // u is never 0 so tan never sees -π/2
+        let u = open_unit(rng);
+        self.median + self.scale * (PI * (u - 0.5)).tan()
+    }
+}
+
+/// Weibull distribution with the given `scale` (λ) and `shape` (k).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Weibull<F = f64> {
+    scale: f64,
+    shape: f64,
+    marker: PhantomData<F>,
+}
+
+impl<F: Float> Weibull<F> {
+    /// `scale` and `shape` must be finite and positive.
+    pub fn new(scale: F, shape: F) -> Option<Self> {
+        let (scale, shape) = (scale.to_f64(), shape.to_f64());
+        if !scale.is_finite() || !shape.is_finite() || scale <= 0.0 || shape <= 0.0 {
+            return None;
+        }
+        Some(Self {
+            scale,
+            shape,
+            marker: PhantomData,
+        })
+    }
+
+    fn sample_f64(&self, rng: &mut fastrand::Rng) -> f64 {
+        self.scale * standard_exponential(rng).powf(1.0 / self.shape)
+    }
+}
+
+/// Pareto distribution with minimum value `scale` (xₘ) and tail index `shape` (α).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pareto<F = f64> {
+    scale: f64,
+    shape: f64,
+    marker: PhantomData<F>,
+}
+
+impl<F: Float> Pareto<F> {
+    /// `scale` and `shape` must be finite and positive.
+    pub fn new(scale: F, shape: F) -> Option<Self> {
+        let (scale, shape) = (scale.to_f64(), shape.to_f64());
+        if !scale.is_finite() || !shape.is_finite() || scale <= 0.0 || shape <= 0.0 {
+            return None;
+        }
+        Some(Self {
+            scale,
+            shape,
+            marker: PhantomData,
+        })
+    }
+
+    fn sample_f64(&self, rng: &mut fastrand::Rng) -> f64 {
+        self.scale * open_unit(rng).powf(-1.0 / self.shape)
+    }
+}
+
+/// Von Mises distribution, the circular analogue of [Normal], for angles in radians around `mean`.
+///
+/// The higher the `concentration` (κ) the tighter the spread, roughly a standard deviation of `1 / sqrt(κ)` radians,
+/// a `concentration` of `0.0` is uniform around the whole circle.
+/// Values are within π of `mean`, so jittering a heading never flips it past the far side:
+///
+/// ```rust
+/// use rantz_random::distributions::{Distribution, VonMises};
+///
+/// let aim = VonMises::new(1.2f32, 50.0).unwrap();
+/// let shot = aim.sample_random();
+/// assert!((shot - 1.2).abs() <= std::f32::consts::PI);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VonMises<F = f64> {
+    mean: f64,
+    concentration: f64,
+    marker: PhantomData<F>,
+}
+
+impl<F: Float> VonMises<F> {
+    /// `mean` must be finite and `concentration` finite and non-negative.
+    pub fn new(mean: F, concentration: F) -> Option<Self> {
+        let (mean, concentration) = (mean.to_f64(), concentration.to_f64());
+        if !mean.is_finite() || !concentration.is_finite() || concentration < 0.0 {
+            return None;
+        }
+        Some(Self {
+            mean,
+            concentration,
+            marker: PhantomData,
+        })
+    }
+
+    fn sample_f64(&self, rng: &mut fastrand::Rng) -> f64 {
+        let kappa = self.concentration;
+        if kappa < 1e-6 {
+            return self.mean + PI * (2.0 * rng.f64() - 1.0);
+        }
+        if kappa > 1e5 {
+            // Indistinguishable from a wrapped normal here, and Best-Fisher loses precision
+            let offset = standard_normal(rng) / kappa.sqrt();
+            return self.mean + wrap_offset(offset);
+        }
+        // Best and Fisher's rejection sampler
+        let tau = 1.0 + (1.0 + 4.0 * kappa * kappa).sqrt();
+        let rho = (tau - (2.0 * tau).sqrt()) / (2.0 * kappa);
+        let r = (1.0 + rho * rho) / (2.0 * rho);
+        loop {
+            let z = (PI * rng.f64()).cos();
+            let f = (1.0 + r * z) / (r + z);
+            let c = kappa * (r - f);
+            let u = open_unit(rng);
+            if c * (2.0 - c) > u || (c / u).ln() + 1.0 >= c {
+                let theta = f.clamp(-1.0, 1.0).acos();
+                return if rng.bool() {
+                    self.mean + theta
+                } else {
+                    self.mean - theta
+                };
+            }
+        }
+    }
+}
+
+/// Wrapped normal distribution, a [Normal] in radians around `mean` folded back onto the circle.
+///
+/// Values are within π of `mean`. For small `std_dev` it's a plain normal,
+/// past about π it's close to uniform around the circle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WrappedNormal<F = f64> {
+    mean: f64,
+    std_dev: f64,
+    marker: PhantomData<F>,
+}
+
+impl<F: Float> WrappedNormal<F> {
+    /// `mean` and `std_dev` must be finite and `std_dev` non-negative.
+    pub fn new(mean: F, std_dev: F) -> Option<Self> {
+        let (mean, std_dev) = (mean.to_f64(), std_dev.to_f64());
+        if !mean.is_finite() || !std_dev.is_finite() || std_dev < 0.0 {
+            return None;
+        }
+        Some(Self {
+            mean,
+            std_dev,
+            marker: PhantomData,
+        })
+    }
+
+    fn sample_f64(&self, rng: &mut fastrand::Rng) -> f64 {
+        self.mean + wrap_offset(self.std_dev * standard_normal(rng))
+    }
+}
+
+/// `offset` folded into `-π..π`.
+fn wrap_offset(offset: f64) -> f64 {
+    (offset + PI).rem_euclid(TAU) - PI
+}
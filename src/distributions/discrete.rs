@@ -0,0 +1,341 @@
+use super::{open_unit, private, standard_gamma, Distribution};
+use std::marker::PhantomData;
+
+/// Integer types the discrete distributions can produce.
+pub trait Integer: Copy + private::Sealed {
+    /// `value`, or the largest `Self` if it doesn't fit.
+    fn from_u64(value: u64) -> Self;
+    /// `None` if `self` is negative or doesn't fit in a `u64`.
+    fn to_u64(self) -> Option<u64>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ident),*) => {
+        $(
+            impl Integer for $t {
+                fn from_u64(value: u64) -> $t {
+                    $t::try_from(value).unwrap_or($t::MAX)
+                }
+
+                fn to_u64(self) -> Option<u64> {
+                    u64::try_from(self).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_sample {
+    ($($t:ident),*) => {
+        $(
+            impl<I: Integer> Distribution<I> for $t<I> {
+                fn sample(&self, rng: &mut fastrand::Rng) -> I {
+                    I::from_u64(self.sample_u64(rng))
+                }
+            }
+        )*
+    };
+}
+
+impl_sample!(Binomial, Geometric, Poisson, Hypergeometric, Zipf);
+
+impl Distribution<bool> for Bernoulli {
+    fn sample(&self, rng: &mut fastrand::Rng) -> bool {
+        rng.f64() < self.p
+    }
+}
+
+/// A single trial that succeeds with probability `p`.
+///
+/// Gives a `bool`, `.map(u8::from)` turns it into `0` or `1`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bernoulli {
+    p: f64,
+}
+
+impl Bernoulli {
+    /// `p` must be in `0.0..=1.0`.
+    pub fn new(p: f64) -> Option<Self> {
+        if !(0.0..=1.0).contains(&p) {
+            return None;
+        }
+        Some(Self { p })
+    }
+}
+
+/// The number of successes in `n` independent trials that each succeed with probability `p`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Binomial<I = u64> {
+    n: u64,
+    p: f64,
+    marker: PhantomData<I>,
+}
+
+impl<I: Integer> Binomial<I> {
+    /// `n` can't be negative and `p` must be in `0.0..=1.0`.
+    pub fn new(n: I, p: f64) -> Option<Self> {
+        let n = n.to_u64()?;
+        if !(0.0..=1.0).contains(&p) {
+            return None;
+        }
+        Some(Self {
+            n,
+            p,
+            marker: PhantomData,
+        })
+    }
+
+    fn sample_u64(&self, rng: &mut fastrand::Rng) -> u64 {
+        binomial(self.n, self.p, rng)
+    }
+}
+
+pub(crate) fn binomial(n: u64, p: f64, rng: &mut fastrand::Rng) -> u64 {
+    if n == 0 || p <= 0.0 {
+        return 0;
+    }
+    if p >= 1.0 {
+        return n;
+    }
+    if p > 0.5 {
+        return n - binomial(n, 1.0 - p, rng);
+    }
+
+    if (n as f64) * p < 16.0 {
+        // Few expected successes, jump between them with geometric gaps
+        let ln_q = (-p).ln_1p();
+        let mut successes = 0;
+        let mut position = 0.0;
+        loop {
+            position += (open_unit(rng).ln() / ln_q).floor() + 1.0;
+            if position > n as f64 {
+                return successes;
+            }
+            successes += 1;
+        }
+    }
+
+    // Knuth's order statistic split, the a-th smallest of n uniforms is Beta(a, b) distributed
+    let a = n / 2 + 1;
+    let b = n + 1 - a;
+    let x = standard_gamma(a as f64, rng);
+    let x = x / (x + standard_gamma(b as f64, rng));
+    if x >= p {
+        binomial(a - 1, p / x, rng)
+    } else {
+        a + binomial(b - 1, (p - x) / (1.0 - x), rng)
+    }
+}
+
+/// The number of failures before the first success, where each trial succeeds with probability `p`.
+///
+/// Saturates at the largest value of the output type.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Geometric<I = u64> {
+    p: f64,
+    marker: PhantomData<I>,
+}
+
+impl<I: Integer> Geometric<I> {
+    /// `p` must be in `0.0..=1.0` and greater than zero.
+    pub fn new(p: f64) -> Option<Self> {
+        if !(p > 0.0 && p <= 1.0) {
+            return None;
+        }
+        Some(Self {
+            p,
+            marker: PhantomData,
+        })
+    }
+
+    fn sample_u64(&self, rng: &mut fastrand::Rng) -> u64 {
+        if self.p == 1.0 {
+            return 0;
+        }
+        // Inversion, saturates at u64::MAX for vanishingly small p
+        (open_unit(rng).ln() / (-self.p).ln_1p()).floor() as u64
+    }
+}
+
+/// The number of events in an interval where they occur independently at an average rate of `lambda`.
+///
+/// Saturates at the largest value of the output type.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Poisson<I = u64> {
+    lambda: f64,
+    marker: PhantomData<I>,
+}
+
+impl<I: Integer> Poisson<I> {
+    /// `lambda` must be finite and positive.
+    pub fn new(lambda: f64) -> Option<Self> {
+        if !lambda.is_finite() || lambda <= 0.0 {
+            return None;
+        }
+        Some(Self {
+            lambda,
+            marker: PhantomData,
+        })
+    }
+
+    fn sample_u64(&self, rng: &mut fastrand::Rng) -> u64 {
+        let mut lambda = self.lambda;
+        let mut count = 0;
+
+        // Knuth's reduction, the m-th arrival time of a unit rate process is Gamma(m) distributed
+        while lambda > 30.0 {
+            let m = (lambda * 7.0 / 8.0).floor() as u64;
+            let x = standard_gamma(m as f64, rng);
+            if x >= lambda {
+                return count + binomial(m - 1, lambda / x, rng);
+            }
+            count += m;
+            lambda -= x;
+        }
+
+        // Multiply uniforms until the product drops below e^-λ
+        let limit = (-lambda).exp();
+        let mut product = open_unit(rng);
+        while product > limit {
+            count += 1;
+            product *= open_unit(rng);
+        }
+        count
+    }
+}
+
+/// The number of successes when drawing `draws` items without replacement
+/// from a `population` that contains `successes` successful items.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hypergeometric<I = u64> {
+    population: u64,
+    successes: u64,
+    draws: u64,
+    marker: PhantomData<I>,
+}
+
+impl<I: Integer> Hypergeometric<I> {
+    /// None of the counts can be negative, and `successes` and `draws` can't exceed `population`.
+    ///
+    /// Sampling is `O(min(draws, population - draws))`.
+    pub fn new(population: I, successes: I, draws: I) -> Option<Self> {
+        let (population, successes, draws) =
+            (population.to_u64()?, successes.to_u64()?, draws.to_u64()?);
+        if successes > population || draws > population {
+            return None;
+        }
+        Some(Self {
+            population,
+            successes,
+            draws,
+            marker: PhantomData,
+        })
+    }
+
+    fn sample_u64(&self, rng: &mut fastrand::Rng) -> u64 {
+        // Drawing most of the population is the same as leaving the rest behind
+        let complement = self.draws > self.population / 2;
+        let draws = if complement {
+            self.population - self.draws
+        } else {
+            self.draws
+        };
+
+        let mut remaining = self.population;
+        let mut remaining_successes = self.successes;
+        let mut drawn_successes = 0;
+        for _ in 0..draws {
+            if rng.u64(0..remaining) < remaining_successes {
+                drawn_successes += 1;
+                remaining_successes -= 1;
+            }
+            remaining -= 1;
+        }
+
+        if complement {
+            self.successes - drawn_successes
+        } else {
+            drawn_successes
+        }
+    }
+}
+
+/// Zipf distribution over the ranks `1..=n`, where rank `k` has weight `1 / k^s`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Zipf<I = u64> {
+    n: u64,
+    s: f64,
+    h_integral_x1: f64,
+    h_integral_n: f64,
+    threshold: f64,
+    marker: PhantomData<I>,
+}
+
+impl<I: Integer> Zipf<I> {
+    /// `n` must be at least 1 and `s` finite and non-negative.
+    pub fn new(n: I, s: f64) -> Option<Self> {
+        let n = n.to_u64()?;
+        if n == 0 || !s.is_finite() || s < 0.0 {
+            return None;
+        }
+
+        let mut zipf = Self {
+            n,
+            s,
+            h_integral_x1: 0.0,
+            h_integral_n: 0.0,
+            threshold: 0.0,
+            marker: PhantomData,
+        };
+        zipf.h_integral_x1 = zipf.h_integral(1.5) - 1.0;
+        zipf.h_integral_n = zipf.h_integral(n as f64 + 0.5);
+        zipf.threshold = 2.0 - zipf.h_integral_inverse(zipf.h_integral(2.5) - zipf.h(2.0));
+        Some(zipf)
+    }
+
+    fn sample_u64(&self, rng: &mut fastrand::Rng) -> u64 {
+        // Rejection-inversion, Hörmann and Derflinger
+        loop {
+            let u = self.h_integral_n + rng.f64() * (self.h_integral_x1 - self.h_integral_n);
+            let x = self.h_integral_inverse(u);
+            let k = (x + 0.5).floor().clamp(1.0, self.n as f64);
+            if k - x <= self.threshold || u >= self.h_integral(k + 0.5) - self.h(k) {
+                return k as u64;
+            }
+        }
+    }
+
+    fn h(&self, x: f64) -> f64 {
+        (-self.s * x.ln()).exp()
+    }
+
+    fn h_integral(&self, x: f64) -> f64 {
+        let ln_x = x.ln();
+        exp_m1_over_x((1.0 - self.s) * ln_x) * ln_x
+    }
+
+    fn h_integral_inverse(&self, x: f64) -> f64 {
+        let t = (x * (1.0 - self.s)).max(-1.0);
+        (ln_1p_over_x(t) * x).exp()
+    }
+}
+
+/// `ln(1 + x) / x`, stable near zero.
+fn ln_1p_over_x(x: f64) -> f64 {
+    if x.abs() > 1e-8 {
+        x.ln_1p() / x
+    } else {
+        1.0 - x * (0.5 - x * (1.0 / 3.0 - 0.25 * x))
+    }
+}
+
+/// `(e^x - 1) / x`, stable near zero.
+fn exp_m1_over_x(x: f64) -> f64 {
+    if x.abs() > 1e-8 {
+        x.exp_m1() / x
+    } else {
+        1.0 + x * 0.5 * (1.0 + x * (1.0 / 3.0) * (1.0 + 0.25 * x))
+    }
+}
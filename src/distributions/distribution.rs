@@ -0,0 +1,364 @@
+use super::{global_rng, private};
+use crate::weighted_table::WeightedTable;
+use std::marker::PhantomData;
+use std::ops::{Range, RangeBounds, RangeInclusive};
+
+/// Anything that can produce values of `T` from a generator.
+///
+/// Distributions compose, so a stat spread can be described once and sampled anywhere:
+///
+/// ```rust
+/// use rantz_random::distributions::{Distribution, Normal};
+///
+/// let strength = Normal::new(10.0f32, 3.0)
+///     .unwrap()
+///     .clamp(3.0, 18.0)
+///     .map(|value| value.round() as u8);
+///
+/// let value = strength.sample_random();
+/// assert!((3..=18).contains(&value));
+/// ```
+pub trait Distribution<T> {
+    /// Draws a value using `rng`.
+    fn sample(&self, rng: &mut fastrand::Rng) -> T;
+
+    /// Draws a value using the global generator.
+    fn sample_random(&self) -> T {
+        self.sample(&mut global_rng())
+    }
+
+    /// An endless iterator of values drawn using `rng`.
+    fn sample_iter<'a>(&'a self, rng: &'a mut fastrand::Rng) -> SampleIter<'a, Self, T>
+    where
+        Self: Sized,
+    {
+        SampleIter {
+            distribution: self,
+            rng,
+            marker: PhantomData,
+        }
+    }
+
+    /// Applies `f` to every value drawn.
+    fn map<U, F>(self, f: F) -> Map<Self, F, T>
+    where
+        Self: Sized,
+        F: Fn(T) -> U,
+    {
+        Map {
+            distribution: self,
+            f,
+            marker: PhantomData,
+        }
+    }
+
+    /// Pins values outside of `min..=max` to the nearest bound.
+    ///
+    /// Panics if `min > max`.
+    fn clamp(self, min: T, max: T) -> Clamp<Self, T>
+    where
+        Self: Sized,
+        T: PartialOrd,
+    {
+        assert!(min <= max, "clamp requires min <= max");
+        Clamp {
+            distribution: self,
+            min,
+            max,
+        }
+    }
+
+    /// Redraws until the value lands in `range`, keeping the shape of the distribution inside it.
+    ///
+    /// Sampling never returns if the distribution can't produce a value in `range`.
+    fn truncated<R>(self, range: R) -> Truncated<Self, R, T>
+    where
+        Self: Sized,
+        R: RangeBounds<T>,
+        T: PartialOrd,
+    {
+        Truncated {
+            distribution: self,
+            range,
+            marker: PhantomData,
+        }
+    }
+
+    /// Draws from this distribution with weight `weight`, otherwise from one of `others` in proportion to theirs.
+    ///
+    /// Returns `None` if the total weight is zero or doesn't fit in a `u32`, see [Mixture].
+    ///
+    /// ```rust
+    /// use rantz_random::distributions::{Distribution, Normal};
+    ///
+    /// let hit = Normal::new(20.0f32, 2.0).unwrap();
+    /// let critical = Normal::new(45.0f32, 5.0).unwrap();
+    /// let damage = hit.mixture(9, [(critical, 1)]).unwrap();
+    ///
+    /// let value = damage.sample_random();
+    /// ```
+    fn mixture<I>(self, weight: u32, others: I) -> Option<Mixture<Self>>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = (Self, u32)>,
+    {
+        Mixture::new(std::iter::once((self, weight)).chain(others))
+    }
+}
+
+impl<T, D: Distribution<T> + ?Sized> Distribution<T> for &D {
+    fn sample(&self, rng: &mut fastrand::Rng) -> T {
+        (**self).sample(rng)
+    }
+}
+
+impl<T, D: Distribution<T> + ?Sized> Distribution<T> for Box<D> {
+    fn sample(&self, rng: &mut fastrand::Rng) -> T {
+        (**self).sample(rng)
+    }
+}
+
+/// Iterator returned by [Distribution::sample_iter].
+pub struct SampleIter<'a, D, T> {
+    distribution: &'a D,
+    rng: &'a mut fastrand::Rng,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T, D: Distribution<T>> Iterator for SampleIter<'_, D, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        Some(self.distribution.sample(self.rng))
+    }
+}
+
+/// Distribution returned by [Distribution::map].
+#[derive(Clone, Copy, Debug)]
+pub struct Map<D, F, T> {
+    distribution: D,
+    f: F,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T, U, D: Distribution<T>, F: Fn(T) -> U> Distribution<U> for Map<D, F, T> {
+    fn sample(&self, rng: &mut fastrand::Rng) -> U {
+        (self.f)(self.distribution.sample(rng))
+    }
+}
+
+/// Distribution returned by [Distribution::clamp].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Clamp<D, T> {
+    distribution: D,
+    min: T,
+    max: T,
+}
+
+impl<T: PartialOrd + Clone, D: Distribution<T>> Distribution<T> for Clamp<D, T> {
+    fn sample(&self, rng: &mut fastrand::Rng) -> T {
+        let value = self.distribution.sample(rng);
+        if value < self.min {
+            self.min.clone()
+        } else if value > self.max {
+            self.max.clone()
+        } else {
+            value
+        }
+    }
+}
+
+/// Distribution returned by [Distribution::truncated].
+#[derive(Clone, Copy, Debug)]
+pub struct Truncated<D, R, T> {
+    distribution: D,
+    range: R,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: PartialOrd, D: Distribution<T>, R: RangeBounds<T>> Distribution<T> for Truncated<D, R, T> {
+    fn sample(&self, rng: &mut fastrand::Rng) -> T {
+        loop {
+            let value = self.distribution.sample(rng);
+            if self.range.contains(&value) {
+                return value;
+            }
+        }
+    }
+}
+
+/// Picks one of its components by weight and draws from it.
+///
+/// Components of different types can be mixed by boxing them:
+///
+/// ```rust
+/// use rantz_random::distributions::{mixture, Distribution, Normal, Uniform};
+///
+/// let damage = mixture([
+///     (Box::new(Normal::new(20.0, 2.0).unwrap()) as Box<dyn Distribution<f64>>, 9),
+///     (Box::new(Uniform::new(40, 60).unwrap().map(f64::from)), 1), // Critical hits
+/// ])
+/// .unwrap();
+///
+/// let value = damage.sample_random();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Mixture<D> {
+    components: Vec<D>,
+    weights: Vec<u32>,
+    total_weight: u32,
+}
+
+impl<D> Mixture<D> {
+    /// Returns `None` if the total weight is zero or doesn't fit in a `u32`.
+    pub fn new(components: impl IntoIterator<Item = (D, u32)>) -> Option<Self> {
+        let mut mixture = Self {
+            components: Vec::new(),
+            weights: Vec::new(),
+            total_weight: 0,
+        };
+        for (component, weight) in components {
+            mixture.total_weight = mixture.total_weight.checked_add(weight)?;
+            mixture.components.push(component);
+            mixture.weights.push(weight);
+        }
+        if mixture.total_weight == 0 {
+            return None;
+        }
+        Some(mixture)
+    }
+}
+
+/// Shorthand for [Mixture::new].
+pub fn mixture<D>(components: impl IntoIterator<Item = (D, u32)>) -> Option<Mixture<D>> {
+    Mixture::new(components)
+}
+
+impl<T, D: Distribution<T>> Distribution<T> for Mixture<D> {
+    fn sample(&self, rng: &mut fastrand::Rng) -> T {
+        let mut n = rng.u32(1..=self.total_weight);
+        for (component, weight) in self.components.iter().zip(&self.weights) {
+            if n <= *weight {
+                return component.sample(rng);
+            }
+            n -= weight;
+        }
+        unreachable!()
+    }
+}
+
+/// Panics if the table is empty or every weight is zero.
+impl<T: PartialEq + Clone> Distribution<T> for WeightedTable<T> {
+    fn sample(&self, rng: &mut fastrand::Rng) -> T {
+        assert!(self.total_weight > 0, "can't sample an empty WeightedTable");
+        self.random_with(rng.u32(1..=self.total_weight)).0
+    }
+}
+
+/// Types [Uniform] can produce.
+pub trait SampleUniform: Copy + PartialOrd + private::Sealed {
+    fn sample_inclusive(rng: &mut fastrand::Rng, low: Self, high: Self) -> Self;
+    fn valid_bounds(low: Self, high: Self) -> bool;
+}
+
+macro_rules! impl_sample_uniform_integer {
+    ($($t:ident),*) => {
+        $(
+            impl SampleUniform for $t {
+                fn sample_inclusive(rng: &mut fastrand::Rng, low: $t, high: $t) -> $t {
+                    rng.$t(low..=high)
+                }
+
+                fn valid_bounds(low: $t, high: $t) -> bool {
+                    low <= high
+                }
+            }
+
+            impl private::Sealed for $t {}
+        )*
+    };
+}
+
+impl_sample_uniform_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_sample_uniform_float {
+    ($($t:ident),*) => {
+        $(
+            impl SampleUniform for $t {
+                fn sample_inclusive(rng: &mut fastrand::Rng, low: $t, high: $t) -> $t {
+                    // Weighted sum rather than low + diff so the full range can't overflow
+                    let t = rng.$t();
+                    (low * (1.0 - t) + high * t).clamp(low, high)
+                }
+
+                fn valid_bounds(low: $t, high: $t) -> bool {
+                    low.is_finite() && high.is_finite() && low <= high
+                }
+            }
+        )*
+    };
+}
+
+impl_sample_uniform_float!(f32, f64);
+
+/// Float ranges are uniform distributions, `low..high` never gives `high`.
+///
+/// Integer ranges are iterators, whose `map` would clash with [Distribution::map], so use [Uniform] for those.
+/// Sampling panics if the range is empty or not finite.
+macro_rules! impl_float_range_distribution {
+    ($($t:ident),*) => {
+        $(
+            impl Distribution<$t> for Range<$t> {
+                fn sample(&self, rng: &mut fastrand::Rng) -> $t {
+                    assert!(
+                        $t::valid_bounds(self.start, self.end) && self.start < self.end,
+                        "empty or infinite range: {:?}",
+                        self
+                    );
+                    loop {
+                        let value = $t::sample_inclusive(rng, self.start, self.end);
+                        if value < self.end {
+                            return value;
+                        }
+                    }
+                }
+            }
+
+            impl Distribution<$t> for RangeInclusive<$t> {
+                fn sample(&self, rng: &mut fastrand::Rng) -> $t {
+                    assert!(
+                        $t::valid_bounds(*self.start(), *self.end()),
+                        "empty or infinite range: {:?}",
+                        self
+                    );
+                    $t::sample_inclusive(rng, *self.start(), *self.end())
+                }
+            }
+        )*
+    };
+}
+
+impl_float_range_distribution!(f32, f64);
+
+/// Every value in `low..=high` is equally likely.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Uniform<T> {
+    low: T,
+    high: T,
+}
+
+impl<T: SampleUniform> Uniform<T> {
+    /// `low` can't be greater than `high`, float bounds must be finite.
+    pub fn new(low: T, high: T) -> Option<Self> {
+        if !T::valid_bounds(low, high) {
+            return None;
+        }
+        Some(Self { low, high })
+    }
+}
+
+impl<T: SampleUniform> Distribution<T> for Uniform<T> {
+    fn sample(&self, rng: &mut fastrand::Rng) -> T {
+        T::sample_inclusive(rng, self.low, self.high)
+    }
+}
@@ -0,0 +1,119 @@
+//! Distributions
+//!
+//! Non-uniform samplers, continuous ones for `f32` and `f64` and discrete ones for any integer type.
+//! Every [Distribution] can draw from the global generator,
+//! which follows [seed](crate::seed), or from an explicit [Rng](crate::Rng) handle:
+//!
+//! ```rust
+//! use rantz_random::distributions::{Distribution, Normal};
+//! use rantz_random::Rng;
+//!
+//! let damage = Normal::new(50.0f32, 5.0).unwrap();
+//!
+//! let global = damage.sample_random(); // From the global generator
+//!
+//! let mut rng = Rng::with_seed(42);
+//! let seeded = damage.sample(&mut rng); // From an explicit handle
+//! ```
+//!
+//! ```rust
+//! use rantz_random::distributions::{Distribution, Poisson};
+//!
+//! let arrivals = Poisson::new(4.5).unwrap();
+//! let customers: u32 = arrivals.sample_random();
+//! ```
+//!
+//! Discrete distributions default to `u64` and saturate if a result doesn't fit the type asked for,
+//! except [Bernoulli], which gives a `bool`.
+//!
+//! Constructors return `None` for parameters the distribution isn't defined for.
+//! Float ranges like `0.0..1.0` are distributions too, for integer ranges use [Uniform].
+
+mod continuous;
+mod discrete;
+mod distribution;
+
+pub use continuous::*;
+pub use discrete::*;
+pub use distribution::*;
+
+/// Floating point types the distributions can produce.
+pub trait Float: Copy + private::Sealed {
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+}
+
+impl Float for f32 {
+    fn from_f64(value: f64) -> f32 {
+        value as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl Float for f64 {
+    fn from_f64(value: f64) -> f64 {
+        value
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// A generator forked from the global one, so draws still follow [seed](crate::seed).
+pub(crate) fn global_rng() -> fastrand::Rng {
+    fastrand::Rng::new()
+}
+
+/// Uniform in `(0, 1]`, safe to take the log of.
+pub(crate) fn open_unit(rng: &mut fastrand::Rng) -> f64 {
+    1.0 - rng.f64()
+}
+
+/// Standard normal via the Marsaglia polar method.
+pub(crate) fn standard_normal(rng: &mut fastrand::Rng) -> f64 {
+    loop {
+        let u = rng.f64() * 2.0 - 1.0;
+        let v = rng.f64() * 2.0 - 1.0;
+        let s = u * u + v * v;
+        if s > 0.0 && s < 1.0 {
+            return u * (-2.0 * s.ln() / s).sqrt();
+        }
+    }
+}
+
+pub(crate) fn standard_exponential(rng: &mut fastrand::Rng) -> f64 {
+    -open_unit(rng).ln()
+}
+
+/// Gamma with unit scale via Marsaglia and Tsang.
+pub(crate) fn standard_gamma(shape: f64, rng: &mut fastrand::Rng) -> f64 {
+    if shape < 1.0 {
+        // Boost to shape + 1 and scale back down
+        return standard_gamma(shape + 1.0, rng) * open_unit(rng).powf(1.0 / shape);
+    }
+
+    let d = shape - 1.0 / 3.0;
+    let c = 1.0 / (9.0 * d).sqrt();
+    loop {
+        let x = standard_normal(rng);
+        let v = 1.0 + c * x;
+        if v <= 0.0 {
+            continue;
+        }
+        let v = v * v * v;
+        let u = open_unit(rng);
+        if u < 1.0 - 0.0331 * x * x * x * x || u.ln() < 0.5 * x * x + d * (1.0 - v + v.ln()) {
+            return d * v;
+        }
+    }
+}
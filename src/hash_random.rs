@@ -0,0 +1,165 @@
+//! Hash Random
+//!
+//! Randomness computed from a seed and a position instead of drawn from a generator.
+//! The same inputs always give the same value, whatever order positions are visited in,
+//! so the content of any tile or chunk can be worked out on demand without storing it:
+//!
+//! ```rust
+//! use rantz_random::hash_random::{hash_2d, hash_chance, hash_range, hash_weighted};
+//! use rantz_random::WeightedTable;
+//!
+//! const WORLD_SEED: u32 = 1234;
+//! let ground: WeightedTable<&str> = [("grass", 8), ("dirt", 3), ("stone", 1)].into_iter().collect();
+//!
+//! let tile = |x: i32, y: i32| {
+//!     let hash = hash_2d(WORLD_SEED, x, y);
+//!     (hash_weighted(hash, &ground), hash_range(hash, 0u8, 3))
+//! };
+//! assert_eq!(tile(-40, 17), tile(-40, 17));
+//!
+//! // A different seed per kind of content keeps them independent
+//! let has_tree = hash_chance(hash_2d(WORLD_SEED + 1, -40, 17), 0.1);
+//! ```
+//!
+//! For many values at one position, seed a generator from the hash with [Rng::with_seed](crate::Rng::with_seed).
+//!
+//! The hash is Squirrel Eiserloh's SquirrelNoise5, positions are combined into one with large primes.
+
+use crate::WeightedTable;
+
+const NOISE_1: u32 = 0xD2A8_0A3F;
+const NOISE_2: u32 = 0xA884_F197;
+const NOISE_3: u32 = 0x6C73_6F4B;
+const NOISE_4: u32 = 0xB79F_3ABB;
+const NOISE_5: u32 = 0x1B56_C4F5;
+
+const PRIME_Y: i32 = 198_491_317;
+const PRIME_Z: i32 = 6_542_989;
+const PRIME_W: i32 = 357_239;
+
+/// A well mixed hash of `x`.
+pub fn hash_u32(seed: u32, x: i32) -> u32 {
+    let mut bits = (x as u32).wrapping_mul(NOISE_1);
+    bits = bits.wrapping_add(seed);
+    bits ^= bits >> 9;
+    bits = bits.wrapping_add(NOISE_2);
+    bits ^= bits >> 11;
+    bits = bits.wrapping_mul(NOISE_3);
+    bits ^= bits >> 13;
+    bits = bits.wrapping_add(NOISE_4);
+    bits ^= bits >> 15;
+    bits = bits.wrapping_mul(NOISE_5);
+    bits ^ (bits >> 17)
+}
+
+/// A well mixed hash of `(x, y)`.
+pub fn hash_2d(seed: u32, x: i32, y: i32) -> u32 {
+    hash_u32(seed, x.wrapping_add(PRIME_Y.wrapping_mul(y)))
+}
+
+/// A well mixed hash of `(x, y, z)`.
+pub fn hash_3d(seed: u32, x: i32, y: i32, z: i32) -> u32 {
+    hash_u32(
+        seed,
+        x.wrapping_add(PRIME_Y.wrapping_mul(y))
+            .wrapping_add(PRIME_Z.wrapping_mul(z)),
+    )
+}
+
+/// A well mixed hash of `(x, y, z, w)`.
+pub fn hash_4d(seed: u32, x: i32, y: i32, z: i32, w: i32) -> u32 {
+    hash_u32(
+        seed,
+        x.wrapping_add(PRIME_Y.wrapping_mul(y))
+            .wrapping_add(PRIME_Z.wrapping_mul(z))
+            .wrapping_add(PRIME_W.wrapping_mul(w)),
+    )
+}
+
+/// `hash` as a uniform value in `0.0..1.0`.
+pub fn hash_f32(hash: u32) -> f32 {
+    (hash >> 8) as f32 / (1u32 << 24) as f32
+}
+
+/// `hash` as a uniform value in `0.0..1.0`.
+pub fn hash_f64(hash: u32) -> f64 {
+    hash as f64 / (1u64 << 32) as f64
+}
+
+/// `hash` as a value between `low` and `high`, following [RandomRange](crate::RandomRange).
+///
+/// Integer types include `high`, floating point types don't.
+/// Panics if an integer `high` is below `low`.
+pub fn hash_range<T: HashRange>(hash: u32, low: T, high: T) -> T {
+    T::hash_range(hash, low, high)
+}
+
+/// `true` with probability `p` across hashes, like [chance](crate::chance::chance).
+pub fn hash_chance(hash: u32, p: f64) -> bool {
+    hash_f64(hash) < p
+}
+
+/// One element of `items`, each equally likely across hashes, or `None` if it's empty.
+pub fn hash_choose<T>(hash: u32, items: &[T]) -> Option<&T> {
+    if items.is_empty() {
+        return None;
+    }
+    items.get(scale(hash, items.len() as u64) as usize)
+}
+
+/// A value from `table` picked in proportion to its weight, or `None` if the table has no weight.
+pub fn hash_weighted<T>(hash: u32, table: &WeightedTable<T>) -> Option<T>
+where
+    T: Clone + PartialEq,
+{
+    if table.total_weight == 0 {
+        return None;
+    }
+    let mut n = scale(hash, table.total_weight as u64) as u32;
+    for (weight, value) in table.weights.iter().zip(&table.values) {
+        if n < *weight {
+            return Some(value.clone());
+        }
+        n -= weight;
+    }
+    None
+}
+
+/// Maps `hash` evenly onto `0..span`.
+fn scale(hash: u32, span: u64) -> u64 {
+    ((hash as u128 * span as u128) >> 32) as u64
+}
+
+/// Types [hash_range] can produce.
+pub trait HashRange: Sized {
+    fn hash_range(hash: u32, low: Self, high: Self) -> Self;
+}
+
+macro_rules! impl_hash_range_int {
+    ($($t:ty),*) => {
+        $(
+            impl HashRange for $t {
+                fn hash_range(hash: u32, low: $t, high: $t) -> $t {
+                    assert!(low <= high, "empty range: {}..={}", low, high);
+                    let span = (high as i128 - low as i128 + 1) as u128;
+                    let offset = (hash as u128 * span) >> 32;
+                    (low as i128 + offset as i128) as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_hash_range_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl HashRange for f32 {
+    fn hash_range(hash: u32, low: f32, high: f32) -> f32 {
+        low + (high - low) * hash_f32(hash)
+    }
+}
+
+impl HashRange for f64 {
+    fn hash_range(hash: u32, low: f64, high: f64) -> f64 {
+        low + (high - low) * hash_f64(hash)
+    }
+}
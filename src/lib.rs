@@ -1,16 +1,61 @@
+pub mod angle;
+pub mod chance;
+#[cfg(feature = "bevy")]
+pub mod color;
+pub mod combinatorics;
+pub mod dice;
+#[cfg(feature = "spatial2d")]
+mod direction_table;
+pub mod distributions;
+pub mod hash_random;
+pub mod noise;
+mod random_collection_impl;
 mod random_impl;
+mod random_in_impl;
 mod random_range_impl;
 mod random_traits;
+#[cfg(feature = "bevy")]
+pub mod scatter;
+#[cfg(feature = "bevy")]
+pub mod shapes;
 mod shuffle_impl;
 mod shuffle_trait;
+#[cfg(feature = "bevy")]
+mod transform_range;
+#[cfg(feature = "bevy")]
+pub mod walk;
 mod weighted_table;
+#[cfg(feature = "spatial2d")]
+pub use direction_table::CompassPoint;
+#[cfg(feature = "spatial2d")]
+pub use direction_table::DirectionTable;
+pub use distributions::Distribution;
+pub use random_impl::random_alphanumeric;
+pub use random_impl::random_option;
+pub use random_impl::random_result;
 pub use random_traits::Random;
 pub use random_traits::RandomContainer;
+pub use random_traits::RandomDirection;
+pub use random_traits::RandomIn;
+pub use random_traits::RandomMap;
 pub use random_traits::RandomRange;
+pub use random_traits::RandomRef;
+pub use random_traits::RandomRotation;
+pub use random_traits::RandomVector;
 pub use random_traits::RandomWeightedContainer;
+#[cfg(feature = "derive")]
+pub use rantz_random_derive::Random;
+#[cfg(feature = "derive")]
+pub use rantz_random_derive::RandomRange;
 pub use shuffle_trait::Shuffle;
+#[cfg(feature = "bevy")]
+pub use transform_range::RotationRange;
+#[cfg(feature = "bevy")]
+pub use transform_range::TransformRange;
 pub use weighted_table::WeightedTable;
 
+pub use fastrand::Rng;
+
 pub fn seed(seed: u64) {
     fastrand::seed(seed);
 }
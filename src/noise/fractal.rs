@@ -0,0 +1,223 @@
+use super::{noise_at, Noise};
+
+/// Shifts each octave so they don't all share a lattice point at the origin.
+const OCTAVE_OFFSET: f32 = 17.31;
+
+/// Where each axis of a [DomainWarp] samples its warp, so the axes move independently.
+const WARP_OFFSETS: [f32; 4] = [0.0, 31.41, 57.72, 83.17];
+
+/// Fractal Brownian motion, layers of `source` at rising frequency and falling amplitude.
+///
+/// Adds fine detail to large features, like rocks on hills on mountains.
+/// The result is normalized so a source within `-1.0..=1.0` stays within it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fbm<N> {
+    pub source: N,
+    octaves: u32,
+    lacunarity: f32,
+    gain: f32,
+}
+
+impl<N: Noise> Fbm<N> {
+    /// 4 octaves, each twice the frequency and half the amplitude of the last.
+    pub fn new(source: N) -> Self {
+        Self {
+            source,
+            octaves: 4,
+            lacunarity: 2.0,
+            gain: 0.5,
+        }
+    }
+
+    /// Number of layers, at least 1.
+    pub fn with_octaves(mut self, octaves: u32) -> Self {
+        self.octaves = octaves.max(1);
+        self
+    }
+
+    /// Frequency multiplier from one octave to the next.
+    pub fn with_lacunarity(mut self, lacunarity: f32) -> Self {
+        self.lacunarity = lacunarity;
+        self
+    }
+
+    /// Amplitude multiplier from one octave to the next, higher is rougher.
+    pub fn with_gain(mut self, gain: f32) -> Self {
+        self.gain = gain;
+        self
+    }
+
+    fn value<const D: usize>(&self, point: [f32; D]) -> f32 {
+        octaves(self.octaves, self.lacunarity, self.gain, point, |p| {
+            noise_at(&self.source, p)
+        })
+    }
+}
+
+impl<N: Noise> Noise for Fbm<N> {
+    fn noise_1d(&self, x: f32) -> f32 {
+        self.value([x])
+    }
+
+    fn noise_2d(&self, x: f32, y: f32) -> f32 {
+        self.value([x, y])
+    }
+
+    fn noise_3d(&self, x: f32, y: f32, z: f32) -> f32 {
+        self.value([x, y, z])
+    }
+
+    fn noise_4d(&self, x: f32, y: f32, z: f32, w: f32) -> f32 {
+        self.value([x, y, z, w])
+    }
+}
+
+/// Ridged noise, [Fbm] with every octave folded so its zero crossings become sharp peaks.
+///
+/// Good for mountain ranges and canyons, the result stays within `-1.0..=1.0` for a source within it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ridged<N> {
+    pub source: N,
+    octaves: u32,
+    lacunarity: f32,
+    gain: f32,
+}
+
+impl<N: Noise> Ridged<N> {
+    /// 4 octaves, each twice the frequency and half the amplitude of the last.
+    pub fn new(source: N) -> Self {
+        Self {
+            source,
+            octaves: 4,
+            lacunarity: 2.0,
+            gain: 0.5,
+        }
+    }
+
+    /// Number of layers, at least 1.
+    pub fn with_octaves(mut self, octaves: u32) -> Self {
+        self.octaves = octaves.max(1);
+        self
+    }
+
+    /// Frequency multiplier from one octave to the next.
+    pub fn with_lacunarity(mut self, lacunarity: f32) -> Self {
+        self.lacunarity = lacunarity;
+        self
+    }
+
+    /// Amplitude multiplier from one octave to the next, higher is rougher.
+    pub fn with_gain(mut self, gain: f32) -> Self {
+        self.gain = gain;
+        self
+    }
+
+    fn value<const D: usize>(&self, point: [f32; D]) -> f32 {
+        let ridges = octaves(self.octaves, self.lacunarity, self.gain, point, |p| {
+            let ridge = 1.0 - noise_at(&self.source, p).abs();
+            ridge * ridge
+        });
+        ridges * 2.0 - 1.0
+    }
+}
+
+impl<N: Noise> Noise for Ridged<N> {
+    fn noise_1d(&self, x: f32) -> f32 {
+        self.value([x])
+    }
+
+    fn noise_2d(&self, x: f32, y: f32) -> f32 {
+        self.value([x, y])
+    }
+
+    fn noise_3d(&self, x: f32, y: f32, z: f32) -> f32 {
+        self.value([x, y, z])
+    }
+
+    fn noise_4d(&self, x: f32, y: f32, z: f32, w: f32) -> f32 {
+        self.value([x, y, z, w])
+    }
+}
+
+/// Samples `source` at a position pushed around by `warp`, swirling and stretching its features.
+///
+/// Each axis is moved by up to `strength` times `warp`'s value there.
+///
+/// ```rust
+/// use rantz_random::noise::{DomainWarp, Fbm, Noise, OpenSimplex2};
+/// use rantz_random::Rng;
+///
+/// let mut rng = Rng::with_seed(9);
+/// let marble = DomainWarp::new(
+///     OpenSimplex2::new_with(&mut rng),
+///     Fbm::new(OpenSimplex2::new_with(&mut rng)),
+///     4.0,
+/// );
+/// let value = marble.noise_2d(0.3, 0.7);
+/// assert!((-1.0..=1.0).contains(&value));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DomainWarp<N, W> {
+    pub source: N,
+    pub warp: W,
+    pub strength: f32,
+}
+
+impl<N: Noise, W: Noise> DomainWarp<N, W> {
+    pub fn new(source: N, warp: W, strength: f32) -> Self {
+        Self {
+            source,
+            warp,
+            strength,
+        }
+    }
+
+    fn value<const D: usize>(&self, point: [f32; D]) -> f32 {
+        let mut warped = point;
+        for (axis, w) in warped.iter_mut().enumerate() {
+            let sample = point.map(|p| p + WARP_OFFSETS[axis]);
+            *w += self.strength * noise_at(&self.warp, sample);
+        }
+        noise_at(&self.source, warped)
+    }
+}
+
+impl<N: Noise, W: Noise> Noise for DomainWarp<N, W> {
+    fn noise_1d(&self, x: f32) -> f32 {
+        self.value([x])
+    }
+
+    fn noise_2d(&self, x: f32, y: f32) -> f32 {
+        self.value([x, y])
+    }
+
+    fn noise_3d(&self, x: f32, y: f32, z: f32) -> f32 {
+        self.value([x, y, z])
+    }
+
+    fn noise_4d(&self, x: f32, y: f32, z: f32, w: f32) -> f32 {
+        self.value([x, y, z, w])
+    }
+}
+
+/// The amplitude weighted average of `sample` over each octave.
+fn octaves<const D: usize>(
+    count: u32,
+    lacunarity: f32,
+    gain: f32,
+    point: [f32; D],
+    mut sample: impl FnMut([f32; D]) -> f32,
+) -> f32 {
+    let mut frequency = 1.0;
+    let mut amplitude = 1.0;
+    let mut total = 0.0;
+    let mut weights = 0.0;
+    for octave in 0..count {
+        let offset = octave as f32 * OCTAVE_OFFSET;
+        total += amplitude * sample(point.map(|p| p * frequency + offset));
+        weights += amplitude;
+        frequency *= lacunarity;
+        amplitude *= gain;
+    }
+    total / weights
+}
@@ -0,0 +1,230 @@
+//! Noise
+//!
+//! Coherent noise, smooth random values that vary continuously with position, for terrain, clouds, camera shake and the like.
+//! Every source takes its seed from the global generator, so one call to [seed](crate::seed) reproduces the whole world:
+//!
+//! ```rust
+//! use rantz_random::noise::{Fbm, Noise, OpenSimplex2};
+//!
+//! rantz_random::seed(7);
+//! let terrain = Fbm::new(OpenSimplex2::new()).with_octaves(5);
+//! let height = terrain.noise_2d(12.5, 40.25);
+//!
+//! rantz_random::seed(7);
+//! let replayed = Fbm::new(OpenSimplex2::new()).with_octaves(5);
+//! assert_eq!(height, replayed.noise_2d(12.5, 40.25));
+//! ```
+//!
+//! ```rust
+//! use rantz_random::noise::{Noise, Perlin};
+//!
+//! // Screen shake, one noise dimension per axis walked along time
+//! let shake = Perlin::with_seed(3);
+//! let time = 1.75;
+//! let offset = (shake.noise_2d(time * 20.0, 0.0), shake.noise_2d(time * 20.0, 100.0));
+//! ```
+//!
+//! - [ValueNoise], [Perlin] and [OpenSimplex2] return values within `-1.0..=1.0` with a feature roughly every unit
+//! - [Worley] returns distances to scattered feature points, or a random value per cell
+//! - [Fbm], [Ridged] and [DomainWarp] combine other sources
+//!
+//! Sources are built with `new()` from the global generator, `new_with(rng)` from an explicit [Rng](crate::Rng),
+//! or `with_seed(seed)` directly.
+
+mod fractal;
+mod perlin;
+mod simplex;
+mod value;
+mod worley;
+
+pub use fractal::*;
+pub use perlin::*;
+pub use simplex::*;
+pub use value::*;
+pub use worley::*;
+
+/// A coherent noise function in one to four dimensions.
+pub trait Noise {
+    fn noise_1d(&self, x: f32) -> f32;
+    fn noise_2d(&self, x: f32, y: f32) -> f32;
+    fn noise_3d(&self, x: f32, y: f32, z: f32) -> f32;
+    fn noise_4d(&self, x: f32, y: f32, z: f32, w: f32) -> f32;
+}
+
+impl<N: Noise + ?Sized> Noise for &N {
+    fn noise_1d(&self, x: f32) -> f32 {
+        (**self).noise_1d(x)
+    }
+
+    fn noise_2d(&self, x: f32, y: f32) -> f32 {
+        (**self).noise_2d(x, y)
+    }
+
+    fn noise_3d(&self, x: f32, y: f32, z: f32) -> f32 {
+        (**self).noise_3d(x, y, z)
+    }
+
+    fn noise_4d(&self, x: f32, y: f32, z: f32, w: f32) -> f32 {
+        (**self).noise_4d(x, y, z, w)
+    }
+}
+
+impl<N: Noise + ?Sized> Noise for Box<N> {
+    fn noise_1d(&self, x: f32) -> f32 {
+        (**self).noise_1d(x)
+    }
+
+    fn noise_2d(&self, x: f32, y: f32) -> f32 {
+        (**self).noise_2d(x, y)
+    }
+
+    fn noise_3d(&self, x: f32, y: f32, z: f32) -> f32 {
+        (**self).noise_3d(x, y, z)
+    }
+
+    fn noise_4d(&self, x: f32, y: f32, z: f32, w: f32) -> f32 {
+        (**self).noise_4d(x, y, z, w)
+    }
+}
+
+/// Large odd constants, one per axis, to spread lattice coordinates across the hash.
+const PRIMES: [u64; 4] = [
+    0x5205_402B_9270_C86F,
+    0x598C_D327_0038_17B5,
+    0x5BCC_226E_9FA0_BACB,
+    0x56CC_5227_E58F_554B,
+];
+
+/// A well mixed hash of a lattice point.
+fn hash<const D: usize>(seed: u64, cell: [i32; D]) -> u64 {
+    let mut h = seed;
+    for (&c, prime) in cell.iter().zip(PRIMES) {
+        h ^= (c as i64 as u64).wrapping_mul(prime);
+    }
+    // SplitMix64 finalizer
+    h ^= h >> 30;
+    h = h.wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    h ^= h >> 27;
+    h = h.wrapping_mul(0x94D0_49BB_1331_11EB);
+    h ^ (h >> 31)
+}
+
+/// A value in `-1.0..=1.0` from the top bits of a hash.
+fn hash_to_signed(hash: u64) -> f32 {
+    (hash >> 40) as f32 / ((1 << 23) as f32 - 0.5) - 1.0
+}
+
+const GRADIENTS_2D: [[f32; 2]; 12] = [
+    [1.0, 0.0],
+    [0.866_025_4, 0.5],
+    [0.5, 0.866_025_4],
+    [0.0, 1.0],
+    [-0.5, 0.866_025_4],
+    [-0.866_025_4, 0.5],
+    [-1.0, 0.0],
+    [-0.866_025_4, -0.5],
+    [-0.5, -0.866_025_4],
+    [0.0, -1.0],
+    [0.5, -0.866_025_4],
+    [0.866_025_4, -0.5],
+];
+
+/// The dot product of a hashed gradient with `offset`.
+///
+/// 1D gradients have magnitudes from `0.125` to `1.0`, 2D ones are 12 unit directions around the circle,
+/// and 3D and 4D ones point to the middle of the edges of a cube, like improved Perlin noise.
+fn gradient_dot<const D: usize>(hash: u64, offset: [f32; D]) -> f32 {
+    let bits = hash as u32;
+    match D {
+        1 => {
+            let magnitude = ((bits & 7) + 1) as f32 / 8.0;
+            if bits & 8 == 0 {
+                magnitude * offset[0]
+            } else {
+                -magnitude * offset[0]
+            }
+        }
+        2 => {
+            let [x, y] = GRADIENTS_2D[(bits % 12) as usize];
+            x * offset[0] + y * offset[1]
+        }
+        _ => {
+            let skip = ((bits >> D) % D as u32) as usize;
+            let mut dot = 0.0;
+            for (axis, &o) in offset.iter().enumerate() {
+                if axis == skip {
+                    continue;
+                }
+                dot += if bits & (1 << axis) == 0 { o } else { -o };
+            }
+            dot
+        }
+    }
+}
+
+/// Quintic smoothstep, flat at both ends so the noise has continuous second derivatives.
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+/// Splits `point` into its lattice cell and the position inside it.
+fn split<const D: usize>(point: [f32; D]) -> ([i32; D], [f32; D]) {
+    let cell = point.map(|p| p.floor() as i32);
+    let mut local = point;
+    for (l, c) in local.iter_mut().zip(cell) {
+        *l -= c as f32;
+    }
+    (cell, local)
+}
+
+/// Blends a value from every corner of a lattice cell, given the corner's offset from the cell and the distance from it to `local`.
+fn interpolate_corners<const D: usize>(
+    local: [f32; D],
+    mut corner: impl FnMut([i32; D], [f32; D]) -> f32,
+) -> f32 {
+    let weights = local.map(fade);
+    let mut total = 0.0;
+    for i in 0..1usize << D {
+        let mut offset = [0; D];
+        let mut distance = local;
+        let mut weight = 1.0;
+        for axis in 0..D {
+            if i & (1 << axis) == 0 {
+                weight *= 1.0 - weights[axis];
+            } else {
+                offset[axis] = 1;
+                distance[axis] -= 1.0;
+                weight *= weights[axis];
+            }
+        }
+        if weight != 0.0 {
+            total += weight * corner(offset, distance);
+        }
+    }
+    total
+}
+
+/// Adds `offset` to `cell`, wrapping at the edges of `i32`.
+fn offset_cell<const D: usize>(cell: [i32; D], offset: [i32; D]) -> [i32; D] {
+    let mut result = cell;
+    for (r, o) in result.iter_mut().zip(offset) {
+        *r = r.wrapping_add(o);
+    }
+    result
+}
+
+/// A seed for a new noise source.
+fn random_seed(rng: &mut fastrand::Rng) -> u64 {
+    rng.u64(..)
+}
+
+/// Calls the method of `noise` matching the length of `point`.
+fn noise_at<N: Noise + ?Sized, const D: usize>(noise: &N, point: [f32; D]) -> f32 {
+    match *point.as_slice() {
+        [x] => noise.noise_1d(x),
+        [x, y] => noise.noise_2d(x, y),
+        [x, y, z] => noise.noise_3d(x, y, z),
+        [x, y, z, w] => noise.noise_4d(x, y, z, w),
+        _ => unreachable!("noise is only defined in one to four dimensions"),
+    }
+}
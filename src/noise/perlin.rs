@@ -0,0 +1,65 @@
+use super::{gradient_dot, hash, interpolate_corners, offset_cell, random_seed, split, Noise};
+use crate::distributions::global_rng;
+
+/// Scales the output in each dimension by the largest value its gradients allow, so it fills `-1.0..=1.0`.
+const SCALE: [f32; 4] = [2.0, std::f32::consts::SQRT_2, 1.0, 2.0 / 3.0];
+
+/// Improved Perlin noise, a random gradient at every integer lattice point blended smoothly between them.
+///
+/// Zero at every lattice point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Perlin {
+    seed: u64,
+}
+
+impl Default for Perlin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Perlin {
+    /// Seeded from the global generator.
+    pub fn new() -> Self {
+        Self::new_with(&mut global_rng())
+    }
+
+    /// Seeded from `rng`.
+    pub fn new_with(rng: &mut fastrand::Rng) -> Self {
+        Self::with_seed(random_seed(rng))
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    fn value<const D: usize>(&self, point: [f32; D]) -> f32 {
+        let (cell, local) = split(point);
+        let value = interpolate_corners(local, |offset, distance| {
+            gradient_dot(hash(self.seed, offset_cell(cell, offset)), distance)
+        });
+        (value * SCALE[D - 1]).clamp(-1.0, 1.0)
+    }
+}
+
+impl Noise for Perlin {
+    fn noise_1d(&self, x: f32) -> f32 {
+        self.value([x])
+    }
+
+    fn noise_2d(&self, x: f32, y: f32) -> f32 {
+        self.value([x, y])
+    }
+
+    fn noise_3d(&self, x: f32, y: f32, z: f32) -> f32 {
+        self.value([x, y, z])
+    }
+
+    fn noise_4d(&self, x: f32, y: f32, z: f32, w: f32) -> f32 {
+        self.value([x, y, z, w])
+    }
+}
@@ -0,0 +1,61 @@
+use super::{hash, hash_to_signed, interpolate_corners, offset_cell, random_seed, split, Noise};
+use crate::distributions::global_rng;
+
+/// Value noise, a random value at every integer lattice point blended smoothly between them.
+///
+/// The cheapest source, but blockier than [Perlin](super::Perlin) since its features line up with the axes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ValueNoise {
+    seed: u64,
+}
+
+impl Default for ValueNoise {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ValueNoise {
+    /// Seeded from the global generator.
+    pub fn new() -> Self {
+        Self::new_with(&mut global_rng())
+    }
+
+    /// Seeded from `rng`.
+    pub fn new_with(rng: &mut fastrand::Rng) -> Self {
+        Self::with_seed(random_seed(rng))
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    fn value<const D: usize>(&self, point: [f32; D]) -> f32 {
+        let (cell, local) = split(point);
+        interpolate_corners(local, |offset, _| {
+            hash_to_signed(hash(self.seed, offset_cell(cell, offset)))
+        })
+    }
+}
+
+impl Noise for ValueNoise {
+    fn noise_1d(&self, x: f32) -> f32 {
+        self.value([x])
+    }
+
+    fn noise_2d(&self, x: f32, y: f32) -> f32 {
+        self.value([x, y])
+    }
+
+    fn noise_3d(&self, x: f32, y: f32, z: f32) -> f32 {
+        self.value([x, y, z])
+    }
+
+    fn noise_4d(&self, x: f32, y: f32, z: f32, w: f32) -> f32 {
+        self.value([x, y, z, w])
+    }
+}
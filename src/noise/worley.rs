@@ -0,0 +1,172 @@
+use super::{hash, hash_to_signed, offset_cell, random_seed, split, Noise};
+use crate::distributions::global_rng;
+
+/// Keeps cell values independent of the feature point positions.
+const SEED_FLIP_VALUE: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// How [Worley] measures distance.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum WorleyDistance {
+    /// Straight line distance, round cells.
+    #[default]
+    Euclidean,
+    /// Sum of the distances along each axis, diamond shaped cells.
+    Manhattan,
+    /// Largest distance along any axis, square cells.
+    Chebyshev,
+}
+
+impl WorleyDistance {
+    fn measure<const D: usize>(self, offset: [f32; D]) -> f32 {
+        let offset = offset.map(f32::abs);
+        match self {
+            WorleyDistance::Euclidean => offset.iter().map(|o| o * o).sum::<f32>().sqrt(),
+            WorleyDistance::Manhattan => offset.iter().sum(),
+            WorleyDistance::Chebyshev => offset.iter().copied().fold(0.0, f32::max),
+        }
+    }
+}
+
+/// What [Worley] returns.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum WorleyOutput {
+    /// Distance to the nearest feature point, `0.0` at the points and growing towards the cell edges.
+    #[default]
+    F1,
+    /// Distance to the second nearest feature point.
+    F2,
+    /// `F2 - F1`, `0.0` along the edges between cells, good for cracks and veins.
+    F2MinusF1,
+    /// A random value in `-1.0..=1.0` shared by everywhere in the nearest feature point's cell, for flat cells like stained glass.
+    CellValue,
+}
+
+/// Worley, or cellular, noise from a randomly placed feature point in every integer lattice cell.
+///
+/// Distances are in lattice units, so [F1](WorleyOutput::F1) is rarely above `1.0`.
+///
+/// ```rust
+/// use rantz_random::noise::{Noise, Worley, WorleyDistance, WorleyOutput};
+///
+/// let cracks = Worley::with_seed(1)
+///     .with_output(WorleyOutput::F2MinusF1)
+///     .with_distance(WorleyDistance::Manhattan);
+/// assert!(cracks.noise_2d(3.5, 1.25) >= 0.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Worley {
+    seed: u64,
+    distance: WorleyDistance,
+    output: WorleyOutput,
+    jitter: f32,
+}
+
+impl Default for Worley {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Worley {
+    /// Seeded from the global generator.
+    pub fn new() -> Self {
+        Self::new_with(&mut global_rng())
+    }
+
+    /// Seeded from `rng`.
+    pub fn new_with(rng: &mut fastrand::Rng) -> Self {
+        Self::with_seed(random_seed(rng))
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            distance: WorleyDistance::Euclidean,
+            output: WorleyOutput::F1,
+            jitter: 1.0,
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn with_distance(mut self, distance: WorleyDistance) -> Self {
+        self.distance = distance;
+        self
+    }
+
+    pub fn with_output(mut self, output: WorleyOutput) -> Self {
+        self.output = output;
+        self
+    }
+
+    /// How far feature points may stray from the middle of their cell, from `0.0` for a regular grid to `1.0` for anywhere in it.
+    pub fn with_jitter(mut self, jitter: f32) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    fn value<const D: usize>(&self, point: [f32; D]) -> f32 {
+        let (cell, local) = split(point);
+        let mut nearest = f32::INFINITY;
+        let mut second = f32::INFINITY;
+        let mut nearest_cell = cell;
+
+        // Every cell within one step, including diagonals
+        for i in 0..3usize.pow(D as u32) {
+            let mut neighbour = [0; D];
+            let mut rest = i;
+            for n in neighbour.iter_mut() {
+                *n = (rest % 3) as i32 - 1;
+                rest /= 3;
+            }
+            let feature_cell = offset_cell(cell, neighbour);
+
+            // 16 bits of the hash for each axis
+            let h = hash(self.seed, feature_cell);
+            let mut offset = [0.0; D];
+            for axis in 0..D {
+                let unit = ((h >> (16 * axis)) & 0xFFFF) as f32 / 65536.0;
+                let feature = neighbour[axis] as f32 + 0.5 + (unit - 0.5) * self.jitter;
+                offset[axis] = feature - local[axis];
+            }
+
+            let distance = self.distance.measure(offset);
+            if distance < nearest {
+                second = nearest;
+                nearest = distance;
+                nearest_cell = feature_cell;
+            } else if distance < second {
+                second = distance;
+            }
+        }
+
+        match self.output {
+            WorleyOutput::F1 => nearest,
+            WorleyOutput::F2 => second,
+            WorleyOutput::F2MinusF1 => second - nearest,
+            WorleyOutput::CellValue => {
+                hash_to_signed(hash(self.seed ^ SEED_FLIP_VALUE, nearest_cell))
+            }
+        }
+    }
+}
+
+impl Noise for Worley {
+    fn noise_1d(&self, x: f32) -> f32 {
+        self.value([x])
+    }
+
+    fn noise_2d(&self, x: f32, y: f32) -> f32 {
+        self.value([x, y])
+    }
+
+    fn noise_3d(&self, x: f32, y: f32, z: f32) -> f32 {
+        self.value([x, y, z])
+    }
+
+    fn noise_4d(&self, x: f32, y: f32, z: f32, w: f32) -> f32 {
+        self.value([x, y, z, w])
+    }
+}
@@ -0,0 +1,86 @@
+use crate::random_traits::{RandomMap, RandomRef};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+fn random_index(len: usize) -> Option<usize> {
+    if len == 0 {
+        None
+    } else {
+        Some(fastrand::usize(0..len))
+    }
+}
+
+// O(1), indexable
+impl<T> RandomRef<T> for [T] {
+    fn random_ref(&self) -> Option<&T> {
+        random_index(self.len()).map(|i| &self[i])
+    }
+}
+
+impl<T> RandomRef<T> for VecDeque<T> {
+    fn random_ref(&self) -> Option<&T> {
+        random_index(self.len()).and_then(|i| self.get(i))
+    }
+}
+
+// O(n), no positional access so these walk the iterator
+impl<T> RandomRef<T> for BTreeSet<T> {
+    fn random_ref(&self) -> Option<&T> {
+        random_index(self.len()).and_then(|i| self.iter().nth(i))
+    }
+}
+
+impl<T, S> RandomRef<T> for HashSet<T, S> {
+    fn random_ref(&self) -> Option<&T> {
+        random_index(self.len()).and_then(|i| self.iter().nth(i))
+    }
+}
+
+impl<K, V> RandomMap<K, V> for BTreeMap<K, V> {
+    fn random_entry(&self) -> Option<(&K, &V)> {
+        random_index(self.len()).and_then(|i| self.iter().nth(i))
+    }
+}
+
+impl<K, V, S> RandomMap<K, V> for HashMap<K, V, S> {
+    fn random_entry(&self) -> Option<(&K, &V)> {
+        random_index(self.len()).and_then(|i| self.iter().nth(i))
+    }
+}
+
+#[cfg(feature = "indexmap")]
+mod indexmap {
+    use super::random_index;
+    use crate::random_traits::{RandomMap, RandomRef};
+    use indexmap::{IndexMap, IndexSet};
+
+    impl<K, V, S> RandomMap<K, V> for IndexMap<K, V, S> {
+        fn random_entry(&self) -> Option<(&K, &V)> {
+            random_index(self.len()).and_then(|i| self.get_index(i))
+        }
+    }
+
+    impl<T, S> RandomRef<T> for IndexSet<T, S> {
+        fn random_ref(&self) -> Option<&T> {
+            random_index(self.len()).and_then(|i| self.get_index(i))
+        }
+    }
+}
+
+#[cfg(feature = "bevy")]
+mod bevy {
+    use super::random_index;
+    use crate::random_traits::{RandomMap, RandomRef};
+    use bevy::utils::{HashMap, HashSet};
+
+    impl<K, V> RandomMap<K, V> for HashMap<K, V> {
+        fn random_entry(&self) -> Option<(&K, &V)> {
+            random_index(self.len()).and_then(|i| self.iter().nth(i))
+        }
+    }
+
+    impl<T> RandomRef<T> for HashSet<T> {
+        fn random_ref(&self) -> Option<&T> {
+            random_index(self.len()).and_then(|i| self.iter().nth(i))
+        }
+    }
+}
@@ -1,4 +1,9 @@
 use crate::{random_traits::RandomContainer, Random};
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+};
+use std::time::Duration;
 
 impl Random for bool {
     fn random() -> bool {
@@ -90,6 +95,159 @@ impl Random for usize {
     }
 }
 
+impl Random for char {
+    fn random() -> char {
+        fastrand::char(..)
+    }
+}
+
+macro_rules! impl_random_nonzero_unsigned {
+    ($($t:ty => $f:ident),*) => {
+        $(
+            impl Random for $t {
+                fn random() -> $t {
+                    <$t>::new(fastrand::$f(1..)).unwrap()
+                }
+            }
+        )*
+    };
+}
+
+impl_random_nonzero_unsigned!(
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128,
+    NonZeroUsize => usize
+);
+
+macro_rules! impl_random_nonzero_signed {
+    ($($t:ty => $f:ident),*) => {
+        $(
+            impl Random for $t {
+                fn random() -> $t {
+                    // Sample from one fewer value and shift the non-negative half up past zero
+                    let n = fastrand::$f(..$f::MAX);
+                    <$t>::new(if n >= 0 { n + 1 } else { n }).unwrap()
+                }
+            }
+        )*
+    };
+}
+
+impl_random_nonzero_signed!(
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroI128 => i128,
+    NonZeroIsize => isize
+);
+
+impl Random for Duration {
+    fn random() -> Duration {
+        Duration::new(fastrand::u64(..), fastrand::u32(..1_000_000_000))
+    }
+}
+
+impl<T> Random for Wrapping<T>
+where
+    T: Random,
+{
+    fn random() -> Wrapping<T> {
+        Wrapping(T::random())
+    }
+}
+
+impl<T> Random for Option<T>
+where
+    T: Random,
+{
+    fn random() -> Option<T> {
+        random_option(0.5)
+    }
+}
+
+impl<T, E> Random for Result<T, E>
+where
+    T: Random,
+    E: Random,
+{
+    fn random() -> Result<T, E> {
+        random_result(0.5)
+    }
+}
+
+impl<T, const N: usize> Random for [T; N]
+where
+    T: Random,
+{
+    fn random() -> [T; N] {
+        std::array::from_fn(|_| T::random())
+    }
+}
+
+macro_rules! impl_random_tuple {
+    ($($t:ident),+) => {
+        impl<$($t),+> Random for ($($t,)+)
+        where
+            $($t: Random),+
+        {
+            fn random() -> ($($t,)+) {
+                ($($t::random(),)+)
+            }
+        }
+    };
+}
+
+impl_random_tuple!(A);
+impl_random_tuple!(A, B);
+impl_random_tuple!(A, B, C);
+impl_random_tuple!(A, B, C, D);
+impl_random_tuple!(A, B, C, D, E);
+impl_random_tuple!(A, B, C, D, E, F);
+impl_random_tuple!(A, B, C, D, E, F, G);
+impl_random_tuple!(A, B, C, D, E, F, G, H);
+impl_random_tuple!(A, B, C, D, E, F, G, H, I);
+impl_random_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_random_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_random_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+/// Returns `Some(T::random())` with the given probability, otherwise `None`.
+///
+/// Probabilities outside of `0.0..=1.0` behave as if clamped.
+pub fn random_option<T>(some_probability: f64) -> Option<T>
+where
+    T: Random,
+{
+    if fastrand::f64() < some_probability {
+        Some(T::random())
+    } else {
+        None
+    }
+}
+
+/// Returns `Ok(T::random())` with the given probability, otherwise `Err(E::random())`.
+///
+/// Probabilities outside of `0.0..=1.0` behave as if clamped.
+pub fn random_result<T, E>(ok_probability: f64) -> Result<T, E>
+where
+    T: Random,
+    E: Random,
+{
+    if fastrand::f64() < ok_probability {
+        Ok(T::random())
+    } else {
+        Err(E::random())
+    }
+}
+
+/// Returns a random ASCII letter or digit, `a-z`, `A-Z` or `0-9`.
+pub fn random_alphanumeric() -> char {
+    fastrand::alphanumeric()
+}
+
 impl<T, U> RandomContainer<T> for U
 where
     U: Clone + IntoIterator<Item = T>,
@@ -99,7 +257,7 @@ where
 
 #[cfg(feature = "spatial2d")]
 mod spatial2d {
-    use crate::Random;
+    use crate::{Random, RandomIn, RandomVector};
     use rantz_spatial2d::prelude::*;
 
     impl Random for Degrees {
@@ -134,18 +292,32 @@ mod spatial2d {
 
     impl Random for Position2D {
         fn random() -> Position2D {
-            let x = fastrand::f32() * f32::MIN + fastrand::f32() * f32::MAX;
-            let y = fastrand::f32() * f32::MIN + fastrand::f32() * f32::MAX;
+            Position2D::new(f32::random(), f32::random())
+        }
+    }
 
-            Position2D::new(x, y)
+    impl RandomVector for Position2D {
+        fn random_in_aabb(min: Position2D, max: Position2D) -> Position2D {
+            Position2D::random_in(min..=max)
+        }
+
+        fn random_in_unit_cube() -> Position2D {
+            Position2D::new(f32::random_in(-1.0..=1.0), f32::random_in(-1.0..=1.0))
+        }
+
+        fn random_finite() -> Position2D {
+            Position2D::new(f32::random_in(..), f32::random_in(..))
         }
     }
 }
 
 #[cfg(feature = "bevy")]
 mod bevy {
-    use crate::Random;
+    use crate::distributions::global_rng;
+    use crate::shapes::{in_cone, on_circle, on_unit_sphere, uniform_quat};
+    use crate::{Random, RandomDirection, RandomIn, RandomRotation, RandomVector};
     use bevy::prelude::*;
+    use std::f32::consts::{PI, TAU};
 
     impl Random for Color {
         fn random() -> Color {
@@ -155,81 +327,166 @@ mod bevy {
 
     impl Random for Vec2 {
         fn random() -> Vec2 {
-            let x = fastrand::f32() * f32::MIN + fastrand::f32() * f32::MAX;
-            let y = fastrand::f32() * f32::MIN + fastrand::f32() * f32::MAX;
-            Vec2::new(x, y)
+            Vec2::new(f32::random(), f32::random())
         }
     }
 
     impl Random for Vec3 {
         fn random() -> Vec3 {
-            let x = fastrand::f32() * f32::MIN + fastrand::f32() * f32::MAX;
-            let y = fastrand::f32() * f32::MIN + fastrand::f32() * f32::MAX;
-            let z = fastrand::f32() * f32::MIN + fastrand::f32() * f32::MAX;
-            Vec3::new(x, y, z)
+            Vec3::new(f32::random(), f32::random(), f32::random())
         }
     }
 
     impl Random for Vec4 {
         fn random() -> Vec4 {
-            let x = fastrand::f32() * f32::MIN + fastrand::f32() * f32::MAX;
-            let y = fastrand::f32() * f32::MIN + fastrand::f32() * f32::MAX;
-            let z = fastrand::f32() * f32::MIN + fastrand::f32() * f32::MAX;
-            let w = fastrand::f32() * f32::MIN + fastrand::f32() * f32::MAX;
-            Vec4::new(x, y, z, w)
+            Vec4::new(f32::random(), f32::random(), f32::random(), f32::random())
+        }
+    }
+
+    macro_rules! impl_random_vector {
+        ($($t:ident),*) => {
+            $(
+                impl RandomVector for $t {
+                    fn random_in_aabb(min: $t, max: $t) -> $t {
+                        $t::random_in(min..=max)
+                    }
+
+                    fn random_in_unit_cube() -> $t {
+                        $t::random_in($t::NEG_ONE..=$t::ONE)
+                    }
+
+                    fn random_finite() -> $t {
+                        $t::random_in(..)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_random_vector!(Vec2, Vec3, Vec4);
+
+    /// Uniform over the unit circle.
+    impl Random for Dir2 {
+        fn random() -> Dir2 {
+            Dir2::new_unchecked(on_circle(&mut global_rng(), 1.0))
+        }
+    }
+
+    /// Uniform over the unit sphere.
+    impl Random for Dir3 {
+        fn random() -> Dir3 {
+            Dir3::new_unchecked(on_unit_sphere(&mut global_rng()).normalize())
+        }
+    }
+
+    /// Uniform over the unit sphere.
+    impl Random for Dir3A {
+        fn random() -> Dir3A {
+            Dir3A::new_unchecked(on_unit_sphere(&mut global_rng()).normalize().into())
+        }
+    }
+
+    /// Uniform over every orientation.
+    impl Random for Quat {
+        fn random() -> Quat {
+            uniform_quat(&mut global_rng())
+        }
+    }
+
+    impl Random for Rot2 {
+        fn random() -> Rot2 {
+            Rot2::radians(fastrand::f32() * TAU)
+        }
+    }
+
+    impl RandomRotation for Quat {
+        fn random_rotation() -> Quat {
+            Quat::random()
+        }
+    }
+
+    impl RandomRotation for Rot2 {
+        fn random_rotation() -> Rot2 {
+            Rot2::random()
+        }
+    }
+
+    impl RandomRotation for Mat2 {
+        fn random_rotation() -> Mat2 {
+            Mat2::from_angle(fastrand::f32() * TAU)
+        }
+    }
+
+    impl RandomRotation for Mat3 {
+        fn random_rotation() -> Mat3 {
+            Mat3::from_quat(Quat::random())
+        }
+    }
+
+    impl RandomDirection for Dir2 {
+        fn random_in_cone(axis: Dir2, half_angle: f32) -> Dir2 {
+            let half_angle = half_angle.clamp(0.0, PI);
+            let angle = (fastrand::f32() * 2.0 - 1.0) * half_angle;
+            Dir2::new_unchecked(Vec2::from_angle(angle).rotate(*axis).normalize())
+        }
+    }
+
+    impl RandomDirection for Dir3 {
+        fn random_in_cone(axis: Dir3, half_angle: f32) -> Dir3 {
+            Dir3::new_unchecked(in_cone(&mut global_rng(), *axis, half_angle).normalize())
         }
     }
 
     impl Random for IVec2 {
         fn random() -> IVec2 {
-            let x = fastrand::i32(i32::MIN..i32::MAX);
-            let y = fastrand::i32(i32::MIN..i32::MAX);
+            let x = fastrand::i32(..);
+            let y = fastrand::i32(..);
             IVec2::new(x, y)
         }
     }
 
     impl Random for IVec3 {
         fn random() -> IVec3 {
-            let x = fastrand::i32(i32::MIN..i32::MAX);
-            let y = fastrand::i32(i32::MIN..i32::MAX);
-            let z = fastrand::i32(i32::MIN..i32::MAX);
+            let x = fastrand::i32(..);
+            let y = fastrand::i32(..);
+            let z = fastrand::i32(..);
             IVec3::new(x, y, z)
         }
     }
 
     impl Random for IVec4 {
         fn random() -> IVec4 {
-            let x = fastrand::i32(i32::MIN..i32::MAX);
-            let y = fastrand::i32(i32::MIN..i32::MAX);
-            let z = fastrand::i32(i32::MIN..i32::MAX);
-            let w = fastrand::i32(i32::MIN..i32::MAX);
+            let x = fastrand::i32(..);
+            let y = fastrand::i32(..);
+            let z = fastrand::i32(..);
+            let w = fastrand::i32(..);
             IVec4::new(x, y, z, w)
         }
     }
 
     impl Random for UVec2 {
         fn random() -> UVec2 {
-            let x = fastrand::u32(u32::MIN..u32::MAX);
-            let y = fastrand::u32(u32::MIN..u32::MAX);
+            let x = fastrand::u32(..);
+            let y = fastrand::u32(..);
             UVec2::new(x, y)
         }
     }
 
     impl Random for UVec3 {
         fn random() -> UVec3 {
-            let x = fastrand::u32(u32::MIN..u32::MAX);
-            let y = fastrand::u32(u32::MIN..u32::MAX);
-            let z = fastrand::u32(u32::MIN..u32::MAX);
+            let x = fastrand::u32(..);
+            let y = fastrand::u32(..);
+            let z = fastrand::u32(..);
             UVec3::new(x, y, z)
         }
     }
 
     impl Random for UVec4 {
         fn random() -> UVec4 {
-            let x = fastrand::u32(u32::MIN..u32::MAX);
-            let y = fastrand::u32(u32::MIN..u32::MAX);
-            let z = fastrand::u32(u32::MIN..u32::MAX);
-            let w = fastrand::u32(u32::MIN..u32::MAX);
+            let x = fastrand::u32(..);
+            let y = fastrand::u32(..);
+            let z = fastrand::u32(..);
+            let w = fastrand::u32(..);
             UVec4::new(x, y, z, w)
         }
     }
@@ -0,0 +1,239 @@
+use crate::random_traits::RandomIn;
+use std::ops::{Bound, RangeBounds};
+use std::time::Duration;
+
+macro_rules! impl_random_in_integer {
+    ($($t:ident),*) => {
+        $(
+            impl RandomIn for $t {
+                fn random_in<R: RangeBounds<$t>>(range: R) -> $t {
+                    fastrand::$t(range)
+                }
+            }
+        )*
+    };
+}
+
+impl_random_in_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, char);
+
+/// Returns a value in the unit interval, `0.0` and `1.0` are only possible when included.
+pub(crate) fn unit_f32(zero_included: bool, one_included: bool) -> f32 {
+    loop {
+        let t = if one_included {
+            fastrand::u32(0..=1 << 24) as f32 / (1 << 24) as f32
+        } else {
+            fastrand::f32()
+        };
+        if zero_included || t > 0.0 {
+            return t;
+        }
+    }
+}
+
+/// Returns a value in the unit interval, `0.0` and `1.0` are only possible when included.
+pub(crate) fn unit_f64(zero_included: bool, one_included: bool) -> f64 {
+    loop {
+        let t = if one_included {
+            fastrand::u64(0..=1 << 53) as f64 / (1u64 << 53) as f64
+        } else {
+            fastrand::f64()
+        };
+        if zero_included || t > 0.0 {
+            return t;
+        }
+    }
+}
+
+/// The next representable value above a float that isn't `NaN` or infinity.
+macro_rules! next_up {
+    ($t:ident, $value:expr) => {{
+        let value: $t = $value;
+        if value == 0.0 {
+            $t::from_bits(1)
+        } else if value > 0.0 {
+            $t::from_bits(value.to_bits() + 1)
+        } else {
+            $t::from_bits(value.to_bits() - 1)
+        }
+    }};
+}
+
+macro_rules! impl_random_in_float {
+    ($($t:ident => $unit:ident),*) => {
+        $(
+            impl RandomIn for $t {
+                fn random_in<R: RangeBounds<$t>>(range: R) -> $t {
+                    let (low, low_included) = match range.start_bound() {
+                        Bound::Included(&low) => (low, true),
+                        Bound::Excluded(&low) => (low, false),
+                        Bound::Unbounded => ($t::MIN, true),
+                    };
+                    let (high, high_included) = match range.end_bound() {
+                        Bound::Included(&high) => (high, true),
+                        Bound::Excluded(&high) => (high, false),
+                        Bound::Unbounded => ($t::MAX, true),
+                    };
+
+                    // Between two excluded bounds there has to be a value above `low` that's still below `high`
+                    let nothing_between = !low_included && !high_included && next_up!($t, low) >= high;
+                    if !(low < high || (low == high && low_included && high_included)) || nothing_between {
+                        panic!("empty range: {:?}..{:?}", range.start_bound(), range.end_bound());
+                    }
+                    if low == high {
+                        return low;
+                    }
+
+                    // Rounding can land exactly on an excluded bound, so reject and retry
+                    loop {
+                        let t = $unit(low_included, high_included);
+                        let diff = high - low;
+                        let value = if diff.is_finite() {
+                            low + diff * t
+                        } else {
+                            low * (1.0 - t) + high * t
+                        }
+                        .clamp(low, high);
+
+                        if (low_included || value != low) && (high_included || value != high) {
+                            return value;
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_random_in_float!(f32 => unit_f32, f64 => unit_f64);
+
+impl RandomIn for Duration {
+    fn random_in<R: RangeBounds<Duration>>(range: R) -> Duration {
+        const NANOS_PER_SEC: u128 = 1_000_000_000;
+        let start = range.start_bound().map(Duration::as_nanos);
+        let end = match range.end_bound() {
+            Bound::Unbounded => Bound::Included(Duration::MAX.as_nanos()),
+            bound => bound.map(Duration::as_nanos),
+        };
+        let nanos = fastrand::u128((start, end));
+        Duration::new(
+            (nanos / NANOS_PER_SEC) as u64,
+            (nanos % NANOS_PER_SEC) as u32,
+        )
+    }
+}
+
+/// The bounds of a single component of a vector range.
+#[cfg(any(feature = "bevy", feature = "spatial2d"))]
+fn component<T, U, R>(range: &R, component: impl Fn(&T) -> U) -> (Bound<U>, Bound<U>)
+where
+    R: RangeBounds<T>,
+{
+    (
+        range.start_bound().map(&component),
+        range.end_bound().map(&component),
+    )
+}
+
+#[cfg(feature = "spatial2d")]
+mod spatial2d {
+    use super::{component, unit_f64};
+    use crate::angle::{Angle, AngleArc};
+    use crate::random_traits::RandomIn;
+    use rantz_spatial2d::prelude::*;
+    use std::ops::{Bound, RangeBounds};
+
+    /// Counterclockwise from the start to the end, so it can cross zero, see [AngleArc].
+    impl RandomIn for Degrees {
+        fn random_in<R: RangeBounds<Degrees>>(range: R) -> Degrees {
+            random_in_arc(&range)
+        }
+    }
+
+    /// Counterclockwise from the start to the end, so it can cross zero, see [AngleArc].
+    impl RandomIn for Radians {
+        fn random_in<R: RangeBounds<Radians>>(range: R) -> Radians {
+            random_in_arc(&range)
+        }
+    }
+
+    impl RandomIn for Position2D {
+        fn random_in<R: RangeBounds<Position2D>>(range: R) -> Position2D {
+            Position2D::new(
+                f32::random_in(component(&range, |position| position.x)),
+                f32::random_in(component(&range, |position| position.y)),
+            )
+        }
+    }
+
+    /// Open ends cover a single turn, a start and end a whole number of turns apart is the full circle.
+    fn random_in_arc<A: Angle, R: RangeBounds<A>>(range: &R) -> A {
+        let (from, from_included) = match range.start_bound() {
+            Bound::Included(&from) => (from, true),
+            Bound::Excluded(&from) => (from, false),
+            Bound::Unbounded => (A::from_f64(0.0), true),
+        };
+        let (to, to_included) = match range.end_bound() {
+            Bound::Included(&to) => (to, true),
+            Bound::Excluded(&to) => (to, false),
+            Bound::Unbounded => (A::from_f64(A::FULL_TURN), false),
+        };
+
+        let arc = AngleArc::counterclockwise(from, to);
+        let (start, sweep, end) = (
+            arc.start().to_f64(),
+            arc.sweep().to_f64(),
+            arc.end().to_f64(),
+        );
+        if sweep == 0.0 {
+            assert!(from_included && to_included, "empty range");
+            return arc.start();
+        }
+        // Neighbouring angles with both ends excluded leave nothing in between
+        let middle = A::from_f64(start + sweep / 2.0).to_f64();
+        assert!(
+            from_included || to_included || (middle != start && middle != end),
+            "empty range"
+        );
+
+        // Rounding can land exactly on an excluded bound, so reject and retry
+        loop {
+            let angle = A::from_f64(start + sweep * unit_f64(from_included, to_included));
+            let value = angle.to_f64();
+            if (from_included || value != start) && (to_included || value != end) {
+                return angle;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "bevy")]
+mod bevy {
+    use super::component;
+    use crate::random_traits::RandomIn;
+    use bevy::prelude::*;
+    use std::ops::RangeBounds;
+
+    macro_rules! impl_random_in_vector {
+        ($($t:ident => $c:ident { $($axis:ident),+ }),*) => {
+            $(
+                impl RandomIn for $t {
+                    fn random_in<R: RangeBounds<$t>>(range: R) -> $t {
+                        $t::new($($c::random_in(component(&range, |v| v.$axis))),+)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_random_in_vector!(
+        Vec2 => f32 { x, y },
+        Vec3 => f32 { x, y, z },
+        Vec4 => f32 { x, y, z, w },
+        IVec2 => i32 { x, y },
+        IVec3 => i32 { x, y, z },
+        IVec4 => i32 { x, y, z, w },
+        UVec2 => u32 { x, y },
+        UVec3 => u32 { x, y, z },
+        UVec4 => u32 { x, y, z, w }
+    );
+}
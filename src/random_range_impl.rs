@@ -2,6 +2,11 @@
 
 use crate::random_traits::Random;
 use crate::random_traits::RandomRange;
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+};
+use std::time::Duration;
 
 impl RandomRange for f32 {
     fn random_range(min: f32, max: f32) -> f32 {
@@ -47,6 +52,12 @@ impl RandomRange for i128 {
     }
 }
 
+impl RandomRange for isize {
+    fn random_range(min: isize, max: isize) -> isize {
+        fastrand::isize(min..=max)
+    }
+}
+
 impl RandomRange for u8 {
     fn random_range(min: u8, max: u8) -> u8 {
         fastrand::u8(min..=max)
@@ -83,23 +94,136 @@ impl RandomRange for usize {
     }
 }
 
+impl RandomRange for char {
+    fn random_range(min: char, max: char) -> char {
+        fastrand::char(min..=max)
+    }
+}
+
+macro_rules! impl_random_range_nonzero_unsigned {
+    ($($t:ty => $f:ident),*) => {
+        $(
+            impl RandomRange for $t {
+                fn random_range(min: $t, max: $t) -> $t {
+                    <$t>::new(fastrand::$f(min.get()..=max.get())).unwrap()
+                }
+            }
+        )*
+    };
+}
+
+impl_random_range_nonzero_unsigned!(
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128,
+    NonZeroUsize => usize
+);
+
+macro_rules! impl_random_range_nonzero_signed {
+    ($($t:ty => $f:ident),*) => {
+        $(
+            impl RandomRange for $t {
+                fn random_range(min: $t, max: $t) -> $t {
+                    let (min, max) = (min.get(), max.get());
+                    if min < 0 && max > 0 {
+                        // Zero is in the range, sample from one fewer value and skip over it
+                        let n = fastrand::$f(min..max);
+                        <$t>::new(if n >= 0 { n + 1 } else { n }).unwrap()
+                    } else {
+                        <$t>::new(fastrand::$f(min..=max)).unwrap()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_random_range_nonzero_signed!(
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroI128 => i128,
+    NonZeroIsize => isize
+);
+
+impl RandomRange for Duration {
+    fn random_range(min: Duration, max: Duration) -> Duration {
+        const NANOS_PER_SEC: u128 = 1_000_000_000;
+        let nanos = fastrand::u128(min.as_nanos()..=max.as_nanos());
+        Duration::new(
+            (nanos / NANOS_PER_SEC) as u64,
+            (nanos % NANOS_PER_SEC) as u32,
+        )
+    }
+}
+
+impl<T> RandomRange for Wrapping<T>
+where
+    T: RandomRange,
+{
+    fn random_range(min: Wrapping<T>, max: Wrapping<T>) -> Wrapping<T> {
+        Wrapping(T::random_range(min.0, max.0))
+    }
+}
+
+impl<T, const N: usize> RandomRange for [T; N]
+where
+    T: RandomRange,
+{
+    fn random_range(min: [T; N], max: [T; N]) -> [T; N] {
+        let mut max = max.into_iter();
+        min.map(|min| T::random_range(min, max.next().unwrap()))
+    }
+}
+
+macro_rules! impl_random_range_tuple {
+    ($($t:ident => $i:tt),+) => {
+        impl<$($t),+> RandomRange for ($($t,)+)
+        where
+            $($t: RandomRange),+
+        {
+            fn random_range(min: ($($t,)+), max: ($($t,)+)) -> ($($t,)+) {
+                ($($t::random_range(min.$i, max.$i),)+)
+            }
+        }
+    };
+}
+
+impl_random_range_tuple!(A => 0);
+impl_random_range_tuple!(A => 0, B => 1);
+impl_random_range_tuple!(A => 0, B => 1, C => 2);
+impl_random_range_tuple!(A => 0, B => 1, C => 2, D => 3);
+impl_random_range_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4);
+impl_random_range_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5);
+impl_random_range_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5, G => 6);
+impl_random_range_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5, G => 6, H => 7);
+impl_random_range_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5, G => 6, H => 7, I => 8);
+impl_random_range_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5, G => 6, H => 7, I => 8, J => 9);
+impl_random_range_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5, G => 6, H => 7, I => 8, J => 9, K => 10);
+impl_random_range_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5, G => 6, H => 7, I => 8, J => 9, K => 10, L => 11);
+
 #[cfg(feature = "spatial2d")]
 mod spatial2d {
+    use crate::angle::AngleArc;
+    use crate::distributions::Distribution;
     use crate::random_range_impl::RandomRange;
     use crate::random_traits::Random;
     use rantz_spatial2d::prelude::*;
 
+    /// Counterclockwise from `min` to `max`, so `350°..10°` crosses zero, see [AngleArc].
     impl RandomRange for Degrees {
         fn random_range(min: Degrees, max: Degrees) -> Degrees {
-            let diff = max - min;
-            min + (diff * f32::random())
+            AngleArc::counterclockwise(min, max).sample_random()
         }
     }
 
+    /// Counterclockwise from `min` to `max`, so it can cross zero, see [AngleArc].
     impl RandomRange for Radians {
         fn random_range(min: Radians, max: Radians) -> Radians {
-            let diff = max - min;
-            min + (diff * f32::random())
+            AngleArc::counterclockwise(min, max).sample_random()
         }
     }
 
@@ -114,7 +238,7 @@ mod spatial2d {
 #[cfg(feature = "bevy")]
 mod bevy {
     use crate::random_range_impl::RandomRange;
-    use crate::random_traits::Random;
+    use crate::random_traits::{Random, RandomIn};
     use bevy::prelude::*;
 
     impl RandomRange for Vec2 {
@@ -138,75 +262,40 @@ mod bevy {
         }
     }
 
+    // Integer vectors sample each component exactly and include `max`, matching the scalar integer impls
     impl RandomRange for UVec2 {
         fn random_range(min: UVec2, max: UVec2) -> UVec2 {
-            let x_diff = max.x - min.x;
-            let y_diff = max.y - min.y;
-            let new_x = (x_diff as f32 * f32::random()) as u32;
-            let new_y = (y_diff as f32 * f32::random()) as u32;
-            min + UVec2::new(new_x, new_y)
+            UVec2::random_in(min..=max)
         }
     }
 
     impl RandomRange for UVec3 {
         fn random_range(min: UVec3, max: UVec3) -> UVec3 {
-            let x_diff = max.x - min.x;
-            let y_diff = max.y - min.y;
-            let z_diff = max.z - min.z;
-            let new_x = (x_diff as f32 * f32::random()) as u32;
-            let new_y = (y_diff as f32 * f32::random()) as u32;
-            let new_z = (z_diff as f32 * f32::random()) as u32;
-            min + UVec3::new(new_x, new_y, new_z)
+            UVec3::random_in(min..=max)
         }
     }
 
     impl RandomRange for UVec4 {
         fn random_range(min: UVec4, max: UVec4) -> UVec4 {
-            let x_diff = max.x - min.x;
-            let y_diff = max.y - min.y;
-            let z_diff = max.z - min.z;
-            let w_diff = max.w - min.w;
-            let new_x = (x_diff as f32 * f32::random()) as u32;
-            let new_y = (y_diff as f32 * f32::random()) as u32;
-            let new_z = (z_diff as f32 * f32::random()) as u32;
-            let new_w = (w_diff as f32 * f32::random()) as u32;
-            min + UVec4::new(new_x, new_y, new_z, new_w)
+            UVec4::random_in(min..=max)
         }
     }
 
     impl RandomRange for IVec2 {
         fn random_range(min: IVec2, max: IVec2) -> IVec2 {
-            let x_diff = max.x - min.x;
-            let y_diff = max.y - min.y;
-            let new_x = (x_diff as f32 * f32::random()) as i32;
-            let new_y = (y_diff as f32 * f32::random()) as i32;
-            min + IVec2::new(new_x, new_y)
+            IVec2::random_in(min..=max)
         }
     }
 
     impl RandomRange for IVec3 {
         fn random_range(min: IVec3, max: IVec3) -> IVec3 {
-            let x_diff = max.x - min.x;
-            let y_diff = max.y - min.y;
-            let z_diff = max.z - min.z;
-            let new_x = (x_diff as f32 * f32::random()) as i32;
-            let new_y = (y_diff as f32 * f32::random()) as i32;
-            let new_z = (z_diff as f32 * f32::random()) as i32;
-            min + IVec3::new(new_x, new_y, new_z)
+            IVec3::random_in(min..=max)
         }
     }
 
     impl RandomRange for IVec4 {
         fn random_range(min: IVec4, max: IVec4) -> IVec4 {
-            let x_diff = max.x - min.x;
-            let y_diff = max.y - min.y;
-            let z_diff = max.z - min.z;
-            let w_diff = max.w - min.w;
-            let new_x = (x_diff as f32 * f32::random()) as i32;
-            let new_y = (y_diff as f32 * f32::random()) as i32;
-            let new_z = (z_diff as f32 * f32::random()) as i32;
-            let new_w = (w_diff as f32 * f32::random()) as i32;
-            min + IVec4::new(new_x, new_y, new_z, new_w)
+            IVec4::random_in(min..=max)
         }
     }
 }
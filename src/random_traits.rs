@@ -1,11 +1,64 @@
+use std::ops::RangeBounds;
+
 pub trait Random {
     fn random() -> Self;
 }
 
+/// Generates a value between `low` and `high`.
+///
+/// Integer types (and other discrete types like `char`) include `high`, floating point types don't.
+/// Use [RandomIn] for explicit control over which bounds are included.
 pub trait RandomRange {
     fn random_range(low: Self, high: Self) -> Self;
 }
 
+/// Generates a value within a standard range, `a..b`, `a..=b`, `a..`, `..b`, `..=b` or `..`.
+///
+/// Every implementation follows the range syntax exactly:
+/// - `a..b` includes `a` and excludes `b`
+/// - `a..=b` includes both `a` and `b`
+/// - Open ends use the whole domain of the type, so `0.0..` is `0.0..=f32::MAX` for floats.
+///   Angles use a single turn, so `..` is `0..360` degrees, and run counterclockwise, so `350..10` crosses zero.
+/// - Vector types apply the bounds to each component independently.
+///
+/// Empty ranges panic.
+///
+/// ```rust
+/// use rantz_random::RandomIn;
+///
+/// let roll = u32::random_in(1..=6);
+/// let index = usize::random_in(..10);
+/// let fraction = f32::random_in(0.0..1.0);
+/// ```
+pub trait RandomIn: Sized {
+    fn random_in<R: RangeBounds<Self>>(range: R) -> Self;
+}
+
+/// Explicit samplers for floating point vectors.
+///
+/// [Random] on these types generates each component in `0.0..1.0`, like `f32::random()`.
+pub trait RandomVector: Sized {
+    /// Uniform inside the axis aligned box between `min` and `max`, both corners included.
+    fn random_in_aabb(min: Self, max: Self) -> Self;
+    /// Uniform inside the cube centered on the origin that spans `-1.0..=1.0` on every axis.
+    fn random_in_unit_cube() -> Self;
+    /// Every component uniform over all finite values, `f32::MIN..=f32::MAX`.
+    fn random_finite() -> Self;
+}
+
+/// Uniformly random rotations, every orientation is equally likely.
+pub trait RandomRotation {
+    fn random_rotation() -> Self;
+}
+
+/// Directions near another direction.
+pub trait RandomDirection: Sized {
+    /// Uniform over the directions within `half_angle` radians of `axis`.
+    ///
+    /// A `half_angle` of `PI` or more covers every direction.
+    fn random_in_cone(axis: Self, half_angle: f32) -> Self;
+}
+
 pub trait RandomContainer<T>: Clone + IntoIterator<Item = T>
 where
     T: Clone,
@@ -76,3 +129,23 @@ where
         None
     }
 }
+
+pub trait RandomRef<T> {
+    fn random_ref(&self) -> Option<&T>;
+}
+
+pub trait RandomMap<K, V> {
+    fn random_entry(&self) -> Option<(&K, &V)>;
+    fn random_key<'a>(&'a self) -> Option<&'a K>
+    where
+        V: 'a,
+    {
+        self.random_entry().map(|(key, _)| key)
+    }
+    fn random_value<'a>(&'a self) -> Option<&'a V>
+    where
+        K: 'a,
+    {
+        self.random_entry().map(|(_, value)| value)
+    }
+}
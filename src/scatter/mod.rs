@@ -0,0 +1,31 @@
+//! Scatter
+//!
+//! Spreads points evenly over an area or volume, without the clumps and gaps of independent uniform points.
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use rantz_random::scatter::PoissonDisk2d;
+//!
+//! let forest = Rect::new(0.0, 0.0, 100.0, 100.0);
+//! let trees = PoissonDisk2d::new(forest, 5.0).generate();
+//!
+//! for (i, a) in trees.iter().enumerate() {
+//!     for b in &trees[i + 1..] {
+//!         assert!(a.distance(*b) >= 5.0);
+//!     }
+//! }
+//! ```
+//!
+//! - [PoissonDisk2d] and [PoissonDisk3d] keep points a minimum distance apart
+//! - [jittered_grid_2d], [stratified_2d] and [latin_hypercube_2d] split the space into cells and fill each one
+//! - [Halton2d] and [Sobol2d] are deterministic low-discrepancy sequences, even at any length
+//!
+//! Every random generator has a `_with` version that takes an explicit [Rng](crate::Rng).
+
+mod poisson;
+mod sequence;
+mod stratified;
+
+pub use poisson::*;
+pub use sequence::*;
+pub use stratified::*;
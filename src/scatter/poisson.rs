@@ -0,0 +1,376 @@
+use crate::distributions::global_rng;
+use crate::shapes::{on_circle, on_unit_sphere};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Extra seeds tried once the active points run out, so separate parts of a domain get filled too.
+const RESEED_ATTEMPTS: u32 = 100;
+
+/// Bridson's Poisson-disk sampling in 2D.
+///
+/// Fills a domain with points that are never closer than the minimum distance to each other,
+/// but are packed tightly enough that there's no room to add another.
+/// The domain is a [Rect], optionally narrowed to a polygon or any region an accept function allows.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use rantz_random::scatter::PoissonDisk2d;
+///
+/// let arena = [
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(50.0, 0.0),
+///     Vec2::new(25.0, 40.0),
+/// ];
+///
+/// // Rocks get sparser towards the top of the arena
+/// let rocks = PoissonDisk2d::new(Rect::new(0.0, 0.0, 50.0, 40.0), 2.0)
+///     .within_polygon(&arena)
+///     .with_radius(6.0, |point| 2.0 + point.y / 10.0)
+///     .generate();
+/// ```
+pub struct PoissonDisk2d<'a> {
+    bounds: Rect,
+    min_distance: f32,
+    max_distance: f32,
+    radius: Option<Box<dyn Fn(Vec2) -> f32 + 'a>>,
+    polygon: Option<Vec<Vec2>>,
+    accept: Option<Box<dyn Fn(Vec2) -> bool + 'a>>,
+    attempts: u32,
+}
+
+impl<'a> PoissonDisk2d<'a> {
+    /// Points anywhere in `bounds`, at least `min_distance` apart.
+    pub fn new(bounds: Rect, min_distance: f32) -> Self {
+        Self {
+            bounds,
+            min_distance,
+            max_distance: min_distance,
+            radius: None,
+            polygon: None,
+            accept: None,
+            attempts: 30,
+        }
+    }
+
+    /// Only keeps points inside the polygon through `vertices`.
+    ///
+    /// The polygon can be convex or concave, the bounds are narrowed to fit around it.
+    pub fn within_polygon(mut self, vertices: &[Vec2]) -> Self {
+        if let Some(first) = vertices.first() {
+            let fit = vertices
+                .iter()
+                .fold(Rect::from_corners(*first, *first), |rect, v| {
+                    rect.union_point(*v)
+                });
+            self.bounds = self.bounds.intersect(fit);
+        }
+        self.polygon = Some(vertices.to_vec());
+        self
+    }
+
+    /// Only keeps points `accept` returns `true` for.
+    pub fn with_accept(mut self, accept: impl Fn(Vec2) -> bool + 'a) -> Self {
+        self.accept = Some(Box::new(accept));
+        self
+    }
+
+    /// Varies the spacing, a point keeps every other point at least `radius(point)` away.
+    ///
+    /// The radius is clamped between the minimum distance and `max_distance`.
+    pub fn with_radius(mut self, max_distance: f32, radius: impl Fn(Vec2) -> f32 + 'a) -> Self {
+        self.max_distance = max_distance.max(self.min_distance);
+        self.radius = Some(Box::new(radius));
+        self
+    }
+
+    /// Candidates tried around each point before giving up on it, 30 by default.
+    ///
+    /// More attempts pack points more tightly, but take longer.
+    pub fn with_attempts(mut self, attempts: u32) -> Self {
+        self.attempts = attempts.max(1);
+        self
+    }
+
+    /// Generates the points using `rng`.
+    pub fn generate_with(&self, rng: &mut fastrand::Rng) -> Vec<Vec2> {
+        let contains = |point: Vec2| {
+            self.bounds.contains(point)
+                && match &self.polygon {
+                    Some(polygon) => polygon_contains(polygon, point),
+                    None => true,
+                }
+                && match &self.accept {
+                    Some(accept) => accept(point),
+                    None => true,
+                }
+        };
+        let radius = |point: Vec2| match &self.radius {
+            Some(radius) => radius(point).clamp(self.min_distance, self.max_distance),
+            None => self.min_distance,
+        };
+        let bounds = self.bounds;
+
+        bridson(
+            rng,
+            Spacing {
+                min_distance: self.min_distance,
+                max_distance: self.max_distance,
+                attempts: self.attempts,
+            },
+            |rng| bounds.min + bounds.size() * Vec2::new(rng.f32(), rng.f32()),
+            |rng| on_circle(rng, 1.0),
+            &contains,
+            &radius,
+        )
+    }
+
+    pub fn generate(&self) -> Vec<Vec2> {
+        self.generate_with(&mut global_rng())
+    }
+
+    /// [generate](Self::generate) as [Position2D](rantz_spatial2d::prelude::Position2D)s.
+    #[cfg(feature = "spatial2d")]
+    pub fn generate_positions(&self) -> Vec<rantz_spatial2d::prelude::Position2D> {
+        self.generate()
+            .into_iter()
+            .map(|point| rantz_spatial2d::prelude::Position2D::new(point.x, point.y))
+            .collect()
+    }
+}
+
+/// Bridson's Poisson-disk sampling in 3D.
+///
+/// Works like [PoissonDisk2d] over the box between `min` and `max`.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use rantz_random::scatter::PoissonDisk3d;
+///
+/// // Stars in a shell around the origin
+/// let stars = PoissonDisk3d::new(Vec3::splat(-50.0), Vec3::splat(50.0), 8.0)
+///     .with_accept(|point| (40.0..=50.0).contains(&point.length()))
+///     .generate();
+/// ```
+pub struct PoissonDisk3d<'a> {
+    min: Vec3,
+    max: Vec3,
+    min_distance: f32,
+    max_distance: f32,
+    radius: Option<Box<dyn Fn(Vec3) -> f32 + 'a>>,
+    accept: Option<Box<dyn Fn(Vec3) -> bool + 'a>>,
+    attempts: u32,
+}
+
+impl<'a> PoissonDisk3d<'a> {
+    /// Points anywhere in the box between `min` and `max`, at least `min_distance` apart.
+    pub fn new(min: Vec3, max: Vec3, min_distance: f32) -> Self {
+        Self {
+            min,
+            max,
+            min_distance,
+            max_distance: min_distance,
+            radius: None,
+            accept: None,
+            attempts: 30,
+        }
+    }
+
+    /// Only keeps points `accept` returns `true` for.
+    pub fn with_accept(mut self, accept: impl Fn(Vec3) -> bool + 'a) -> Self {
+        self.accept = Some(Box::new(accept));
+        self
+    }
+
+    /// Varies the spacing, a point keeps every other point at least `radius(point)` away.
+    ///
+    /// The radius is clamped between the minimum distance and `max_distance`.
+    pub fn with_radius(mut self, max_distance: f32, radius: impl Fn(Vec3) -> f32 + 'a) -> Self {
+        self.max_distance = max_distance.max(self.min_distance);
+        self.radius = Some(Box::new(radius));
+        self
+    }
+
+    /// Candidates tried around each point before giving up on it, 30 by default.
+    pub fn with_attempts(mut self, attempts: u32) -> Self {
+        self.attempts = attempts.max(1);
+        self
+    }
+
+    /// Generates the points using `rng`.
+    pub fn generate_with(&self, rng: &mut fastrand::Rng) -> Vec<Vec3> {
+        let contains = |point: Vec3| {
+            point.cmpge(self.min).all()
+                && point.cmple(self.max).all()
+                && match &self.accept {
+                    Some(accept) => accept(point),
+                    None => true,
+                }
+        };
+        let radius = |point: Vec3| match &self.radius {
+            Some(radius) => radius(point).clamp(self.min_distance, self.max_distance),
+            None => self.min_distance,
+        };
+        let (min, size) = (self.min, self.max - self.min);
+
+        bridson(
+            rng,
+            Spacing {
+                min_distance: self.min_distance,
+                max_distance: self.max_distance,
+                attempts: self.attempts,
+            },
+            |rng| min + size * Vec3::new(rng.f32(), rng.f32(), rng.f32()),
+            on_unit_sphere,
+            &contains,
+            &radius,
+        )
+    }
+
+    pub fn generate(&self) -> Vec<Vec3> {
+        self.generate_with(&mut global_rng())
+    }
+}
+
+/// Even-odd rule, so either winding works.
+fn polygon_contains(vertices: &[Vec2], point: Vec2) -> bool {
+    let mut inside = false;
+    for i in 0..vertices.len() {
+        let a = vertices[i];
+        let b = vertices[(i + 1) % vertices.len()];
+        if (a.y > point.y) != (b.y > point.y) {
+            let x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+            if point.x < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+struct Spacing {
+    min_distance: f32,
+    max_distance: f32,
+    attempts: u32,
+}
+
+/// The vector operations [bridson] needs, shared by [Vec2] and [Vec3].
+trait GridPoint: Copy + std::ops::Add<Output = Self> + std::ops::Mul<f32, Output = Self> {
+    const DIMENSIONS: i32;
+    type Cell: Copy + Eq + std::hash::Hash;
+
+    fn cell(self, size: f32) -> Self::Cell;
+    fn neighbours(cell: Self::Cell, reach: i32) -> impl Iterator<Item = Self::Cell>;
+    fn distance(self, other: Self) -> f32;
+}
+
+impl GridPoint for Vec2 {
+    const DIMENSIONS: i32 = 2;
+    type Cell = IVec2;
+
+    fn cell(self, size: f32) -> IVec2 {
+        (self / size).floor().as_ivec2()
+    }
+
+    fn neighbours(cell: IVec2, reach: i32) -> impl Iterator<Item = IVec2> {
+        (-reach..=reach).flat_map(move |x| (-reach..=reach).map(move |y| cell + IVec2::new(x, y)))
+    }
+
+    fn distance(self, other: Vec2) -> f32 {
+        Vec2::distance(self, other)
+    }
+}
+
+impl GridPoint for Vec3 {
+    const DIMENSIONS: i32 = 3;
+    type Cell = IVec3;
+
+    fn cell(self, size: f32) -> IVec3 {
+        (self / size).floor().as_ivec3()
+    }
+
+    fn neighbours(cell: IVec3, reach: i32) -> impl Iterator<Item = IVec3> {
+        (-reach..=reach).flat_map(move |x| {
+            (-reach..=reach)
+                .flat_map(move |y| (-reach..=reach).map(move |z| cell + IVec3::new(x, y, z)))
+        })
+    }
+
+    fn distance(self, other: Vec3) -> f32 {
+        Vec3::distance(self, other)
+    }
+}
+
+fn bridson<P: GridPoint>(
+    rng: &mut fastrand::Rng,
+    spacing: Spacing,
+    in_bounds: impl Fn(&mut fastrand::Rng) -> P,
+    direction: impl Fn(&mut fastrand::Rng) -> P,
+    contains: &dyn Fn(P) -> bool,
+    radius: &dyn Fn(P) -> f32,
+) -> Vec<P> {
+    let mut points: Vec<(P, f32)> = Vec::new();
+    if spacing.min_distance.is_nan() || spacing.min_distance <= 0.0 {
+        return Vec::new();
+    }
+
+    // Small enough cells that each holds at most one point
+    let cell_size = spacing.min_distance / (P::DIMENSIONS as f32).sqrt();
+    let reach = (spacing.max_distance / cell_size).ceil() as i32;
+    let mut grid: HashMap<P::Cell, usize> = HashMap::new();
+    let fits = |points: &[(P, f32)], grid: &HashMap<P::Cell, usize>, point: P, distance: f32| {
+        contains(point)
+            && P::neighbours(point.cell(cell_size), reach).all(|cell| match grid.get(&cell) {
+                Some(&i) => {
+                    let (other, other_distance) = points[i];
+                    point.distance(other) >= distance.max(other_distance)
+                }
+                None => true,
+            })
+    };
+
+    let mut active = Vec::new();
+    let mut reseeds = 0;
+    while reseeds < RESEED_ATTEMPTS {
+        if active.is_empty() {
+            let seed = in_bounds(rng);
+            let distance = radius(seed);
+            if fits(&points, &grid, seed, distance) {
+                grid.insert(seed.cell(cell_size), points.len());
+                active.push(points.len());
+                points.push((seed, distance));
+            } else {
+                reseeds += 1;
+            }
+            continue;
+        }
+
+        // Work from any active point, swapping it to the end so dropping it is cheap
+        let slot = rng.usize(0..active.len());
+        let last = active.len() - 1;
+        active.swap(slot, last);
+        let (center, center_distance) = points[active[last]];
+
+        let found = (0..spacing.attempts).find_map(|_| {
+            // Uniform over the shell between one and two radii out
+            let inner = center_distance.powi(P::DIMENSIONS);
+            let outer = (2.0 * center_distance).powi(P::DIMENSIONS);
+            let reach = (inner + rng.f32() * (outer - inner)).powf(1.0 / P::DIMENSIONS as f32);
+            let candidate = center + direction(rng) * reach;
+            let distance = radius(candidate);
+            fits(&points, &grid, candidate, distance).then_some((candidate, distance))
+        });
+
+        match found {
+            Some((candidate, distance)) => {
+                grid.insert(candidate.cell(cell_size), points.len());
+                active.push(points.len());
+                points.push((candidate, distance));
+            }
+            None => {
+                active.pop();
+            }
+        }
+    }
+
+    points.into_iter().map(|(point, _)| point).collect()
+}
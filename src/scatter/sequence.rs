@@ -0,0 +1,279 @@
+use crate::distributions::global_rng;
+use bevy::prelude::*;
+
+/// The Halton sequence in the unit square, using bases 2 and 3.
+///
+/// A deterministic, endless sequence where every prefix covers the square evenly,
+/// so points can be added one at a time without clumping.
+/// Scale the points to place them:
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use rantz_random::scatter::Halton2d;
+///
+/// let field = Rect::new(0.0, 0.0, 100.0, 50.0);
+/// let flowers = Halton2d::new()
+///     .take(64)
+///     .map(|point| field.min + point * field.size())
+///     .collect::<Vec<_>>();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Halton2d {
+    index: u32,
+    shift: Vec2,
+}
+
+impl Default for Halton2d {
+    fn default() -> Self {
+        Self {
+            index: 1,
+            shift: Vec2::ZERO,
+        }
+    }
+}
+
+impl Halton2d {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Offsets every point by the same random amount, wrapping around the square.
+    ///
+    /// Keeps the even coverage while giving a different set of points for each `rng`.
+    pub fn randomized_with(mut self, rng: &mut fastrand::Rng) -> Self {
+        self.shift = Vec2::new(rng.f32(), rng.f32());
+        self
+    }
+
+    /// [randomized_with](Self::randomized_with) using the global generator.
+    pub fn randomized(self) -> Self {
+        self.randomized_with(&mut global_rng())
+    }
+}
+
+impl Iterator for Halton2d {
+    type Item = Vec2;
+
+    fn next(&mut self) -> Option<Vec2> {
+        let point = Vec2::new(
+            radical_inverse(self.index, 2),
+            radical_inverse(self.index, 3),
+        );
+        self.index = self.index.checked_add(1)?;
+        Some((point + self.shift).fract())
+    }
+}
+
+/// The Halton sequence in the unit cube, using bases 2, 3 and 5.
+///
+/// Works like [Halton2d].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Halton3d {
+    index: u32,
+    shift: Vec3,
+}
+
+impl Default for Halton3d {
+    fn default() -> Self {
+        Self {
+            index: 1,
+            shift: Vec3::ZERO,
+        }
+    }
+}
+
+impl Halton3d {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Offsets every point by the same random amount, wrapping around the cube.
+    pub fn randomized_with(mut self, rng: &mut fastrand::Rng) -> Self {
+        self.shift = Vec3::new(rng.f32(), rng.f32(), rng.f32());
+        self
+    }
+
+    /// [randomized_with](Self::randomized_with) using the global generator.
+    pub fn randomized(self) -> Self {
+        self.randomized_with(&mut global_rng())
+    }
+}
+
+impl Iterator for Halton3d {
+    type Item = Vec3;
+
+    fn next(&mut self) -> Option<Vec3> {
+        let point = Vec3::new(
+            radical_inverse(self.index, 2),
+            radical_inverse(self.index, 3),
+            radical_inverse(self.index, 5),
+        );
+        self.index = self.index.checked_add(1)?;
+        Some((point + self.shift).fract())
+    }
+}
+
+/// The Sobol sequence in the unit square.
+///
+/// Like [Halton2d] but more even in power of two sized batches, which suits kernels like ambient occlusion.
+/// The first point is the origin.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sobol2d {
+    sobol: Sobol<2>,
+    shift: Vec2,
+}
+
+impl Default for Sobol2d {
+    fn default() -> Self {
+        Self {
+            sobol: Sobol::new(),
+            shift: Vec2::ZERO,
+        }
+    }
+}
+
+impl Sobol2d {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Offsets every point by the same random amount, wrapping around the square.
+    pub fn randomized_with(mut self, rng: &mut fastrand::Rng) -> Self {
+        self.shift = Vec2::new(rng.f32(), rng.f32());
+        self
+    }
+
+    /// [randomized_with](Self::randomized_with) using the global generator.
+    pub fn randomized(self) -> Self {
+        self.randomized_with(&mut global_rng())
+    }
+}
+
+impl Iterator for Sobol2d {
+    type Item = Vec2;
+
+    fn next(&mut self) -> Option<Vec2> {
+        let [x, y] = self.sobol.next()?;
+        Some((Vec2::new(x, y) + self.shift).fract())
+    }
+}
+
+/// The Sobol sequence in the unit cube.
+///
+/// Works like [Sobol2d].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sobol3d {
+    sobol: Sobol<3>,
+    shift: Vec3,
+}
+
+impl Default for Sobol3d {
+    fn default() -> Self {
+        Self {
+            sobol: Sobol::new(),
+            shift: Vec3::ZERO,
+        }
+    }
+}
+
+impl Sobol3d {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Offsets every point by the same random amount, wrapping around the cube.
+    pub fn randomized_with(mut self, rng: &mut fastrand::Rng) -> Self {
+        self.shift = Vec3::new(rng.f32(), rng.f32(), rng.f32());
+        self
+    }
+
+    /// [randomized_with](Self::randomized_with) using the global generator.
+    pub fn randomized(self) -> Self {
+        self.randomized_with(&mut global_rng())
+    }
+}
+
+impl Iterator for Sobol3d {
+    type Item = Vec3;
+
+    fn next(&mut self) -> Option<Vec3> {
+        let [x, y, z] = self.sobol.next()?;
+        Some((Vec3::new(x, y, z) + self.shift).fract())
+    }
+}
+
+/// `index` with its digits in `base` mirrored around the decimal point.
+fn radical_inverse(mut index: u32, base: u32) -> f32 {
+    let mut result = 0.0f64;
+    let mut scale = 1.0 / base as f64;
+    while index > 0 {
+        result += (index % base) as f64 * scale;
+        index /= base;
+        scale /= base as f64;
+    }
+    // Rounding to f32 mustn't reach 1.0
+    (result as f32).min(1.0 - f32::EPSILON / 2.0)
+}
+
+/// Direction numbers for the first three dimensions, from Joe and Kuo.
+/// Each is `(degree, coefficients, initial values)` of a primitive polynomial.
+const SOBOL_POLYNOMIALS: [(u32, u32, [u32; 2]); 2] = [(1, 0, [1, 0]), (2, 1, [1, 3])];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Sobol<const D: usize> {
+    index: u32,
+    directions: [[u32; 32]; D],
+    state: [u32; D],
+}
+
+impl<const D: usize> Sobol<D> {
+    fn new() -> Self {
+        let directions = std::array::from_fn(|dimension| {
+            let mut v = [0u32; 32];
+            if dimension == 0 {
+                for (k, v) in v.iter_mut().enumerate() {
+                    *v = 1 << (31 - k);
+                }
+                return v;
+            }
+
+            let (degree, coefficients, initial) = SOBOL_POLYNOMIALS[dimension - 1];
+            let degree = degree as usize;
+            for k in 0..32 {
+                v[k] = if k < degree {
+                    initial[k] << (31 - k)
+                } else {
+                    let mut value = v[k - degree] ^ (v[k - degree] >> degree);
+                    for j in 1..degree {
+                        if (coefficients >> (degree - 1 - j)) & 1 == 1 {
+                            value ^= v[k - j];
+                        }
+                    }
+                    value
+                };
+            }
+            v
+        });
+
+        Self {
+            index: 0,
+            directions,
+            state: [0; D],
+        }
+    }
+
+    fn next(&mut self) -> Option<[f32; D]> {
+        let point = self.state.map(|x| (x >> 8) as f32 / (1 << 24) as f32);
+
+        // Gray code order, each step flips the direction for the lowest zero bit of the index
+        let bit = self.index.trailing_ones() as usize;
+        if bit >= 32 {
+            return None;
+        }
+        for (x, directions) in self.state.iter_mut().zip(&self.directions) {
+            *x ^= directions[bit];
+        }
+        self.index += 1;
+        Some(point)
+    }
+}
@@ -0,0 +1,128 @@
+use crate::distributions::global_rng;
+use bevy::prelude::*;
+
+/// One point in every cell of a `cells.x` by `cells.y` grid over `bounds`, in rows from `bounds.min`.
+///
+/// Each point starts at the center of its cell and moves up to `jitter` of the way to the cell's edge,
+/// so `0.0` is a regular grid and `1.0` is anywhere in the cell, see [stratified_2d].
+pub fn jittered_grid_2d(bounds: Rect, cells: UVec2, jitter: f32) -> Vec<Vec2> {
+    jittered_grid_2d_with(&mut global_rng(), bounds, cells, jitter)
+}
+
+/// [jittered_grid_2d] using `rng`.
+pub fn jittered_grid_2d_with(
+    rng: &mut fastrand::Rng,
+    bounds: Rect,
+    cells: UVec2,
+    jitter: f32,
+) -> Vec<Vec2> {
+    let cell = bounds.size() / cells.as_vec2();
+    let jitter = jitter.clamp(0.0, 1.0);
+    let mut points = Vec::with_capacity((cells.x * cells.y) as usize);
+    for y in 0..cells.y {
+        for x in 0..cells.x {
+            let offset = (Vec2::new(rng.f32(), rng.f32()) * 2.0 - Vec2::ONE) * jitter;
+            let center = UVec2::new(x, y).as_vec2() + Vec2::splat(0.5);
+            points.push(bounds.min + (center + offset / 2.0) * cell);
+        }
+    }
+    points
+}
+
+/// One point in every cell of a `cells.x` by `cells.y` by `cells.z` grid over the box between `min` and `max`.
+///
+/// Jitter works like [jittered_grid_2d].
+pub fn jittered_grid_3d(min: Vec3, max: Vec3, cells: UVec3, jitter: f32) -> Vec<Vec3> {
+    jittered_grid_3d_with(&mut global_rng(), min, max, cells, jitter)
+}
+
+/// [jittered_grid_3d] using `rng`.
+pub fn jittered_grid_3d_with(
+    rng: &mut fastrand::Rng,
+    min: Vec3,
+    max: Vec3,
+    cells: UVec3,
+    jitter: f32,
+) -> Vec<Vec3> {
+    let cell = (max - min) / cells.as_vec3();
+    let jitter = jitter.clamp(0.0, 1.0);
+    let mut points = Vec::with_capacity((cells.x * cells.y * cells.z) as usize);
+    for z in 0..cells.z {
+        for y in 0..cells.y {
+            for x in 0..cells.x {
+                let offset =
+                    (Vec3::new(rng.f32(), rng.f32(), rng.f32()) * 2.0 - Vec3::ONE) * jitter;
+                let center = UVec3::new(x, y, z).as_vec3() + Vec3::splat(0.5);
+                points.push(min + (center + offset / 2.0) * cell);
+            }
+        }
+    }
+    points
+}
+
+/// A uniform point in every cell of a `cells.x` by `cells.y` grid over `bounds`.
+pub fn stratified_2d(bounds: Rect, cells: UVec2) -> Vec<Vec2> {
+    jittered_grid_2d(bounds, cells, 1.0)
+}
+
+/// [stratified_2d] using `rng`.
+pub fn stratified_2d_with(rng: &mut fastrand::Rng, bounds: Rect, cells: UVec2) -> Vec<Vec2> {
+    jittered_grid_2d_with(rng, bounds, cells, 1.0)
+}
+
+/// A uniform point in every cell of a `cells.x` by `cells.y` by `cells.z` grid over the box between `min` and `max`.
+pub fn stratified_3d(min: Vec3, max: Vec3, cells: UVec3) -> Vec<Vec3> {
+    jittered_grid_3d(min, max, cells, 1.0)
+}
+
+/// [stratified_3d] using `rng`.
+pub fn stratified_3d_with(
+    rng: &mut fastrand::Rng,
+    min: Vec3,
+    max: Vec3,
+    cells: UVec3,
+) -> Vec<Vec3> {
+    jittered_grid_3d_with(rng, min, max, cells, 1.0)
+}
+
+/// `count` points over `bounds` with exactly one in each of `count` equal columns and in each of `count` equal rows.
+pub fn latin_hypercube_2d(bounds: Rect, count: usize) -> Vec<Vec2> {
+    latin_hypercube_2d_with(&mut global_rng(), bounds, count)
+}
+
+/// [latin_hypercube_2d] using `rng`.
+pub fn latin_hypercube_2d_with(rng: &mut fastrand::Rng, bounds: Rect, count: usize) -> Vec<Vec2> {
+    let [x, y] = latin_strata(rng, count);
+    (0..count)
+        .map(|i| bounds.min + Vec2::new(x[i], y[i]) * bounds.size())
+        .collect()
+}
+
+/// `count` points in the box between `min` and `max` with exactly one in each of `count` equal slices along every axis.
+pub fn latin_hypercube_3d(min: Vec3, max: Vec3, count: usize) -> Vec<Vec3> {
+    latin_hypercube_3d_with(&mut global_rng(), min, max, count)
+}
+
+/// [latin_hypercube_3d] using `rng`.
+pub fn latin_hypercube_3d_with(
+    rng: &mut fastrand::Rng,
+    min: Vec3,
+    max: Vec3,
+    count: usize,
+) -> Vec<Vec3> {
+    let [x, y, z] = latin_strata(rng, count);
+    (0..count)
+        .map(|i| min + Vec3::new(x[i], y[i], z[i]) * (max - min))
+        .collect()
+}
+
+/// For each axis, a shuffled list holding one uniform value from each of the `count` slices of `0.0..1.0`.
+fn latin_strata<const D: usize>(rng: &mut fastrand::Rng, count: usize) -> [Vec<f32>; D] {
+    std::array::from_fn(|_| {
+        let mut slices = (0..count)
+            .map(|slice| (slice as f32 + rng.f32()) / count as f32)
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut slices);
+        slices
+    })
+}
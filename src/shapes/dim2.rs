@@ -0,0 +1,317 @@
+use super::{cumulative, pick_cumulative};
+use crate::distributions::global_rng;
+use bevy::prelude::*;
+use std::f32::consts::{PI, TAU};
+
+/// Uniform random points in 2D shapes.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use rantz_random::shapes::RandomPoint2d;
+///
+/// let ring = Annulus::new(5.0, 10.0);
+/// let point = ring.random_interior();
+///
+/// assert!((5.0..=10.0).contains(&point.length()));
+/// ```
+pub trait RandomPoint2d {
+    /// A point inside the shape, edges included, drawn using `rng`.
+    fn random_interior_with(&self, rng: &mut fastrand::Rng) -> Vec2;
+
+    /// A point on the edge of the shape, drawn using `rng`.
+    fn random_boundary_with(&self, rng: &mut fastrand::Rng) -> Vec2;
+
+    /// A point inside the shape, edges included.
+    fn random_interior(&self) -> Vec2 {
+        self.random_interior_with(&mut global_rng())
+    }
+
+    /// A point on the edge of the shape.
+    fn random_boundary(&self) -> Vec2 {
+        self.random_boundary_with(&mut global_rng())
+    }
+
+    /// [random_interior](Self::random_interior) as a [Position2D](rantz_spatial2d::prelude::Position2D).
+    #[cfg(feature = "spatial2d")]
+    fn random_interior_position(&self) -> rantz_spatial2d::prelude::Position2D {
+        let point = self.random_interior();
+        rantz_spatial2d::prelude::Position2D::new(point.x, point.y)
+    }
+
+    /// [random_boundary](Self::random_boundary) as a [Position2D](rantz_spatial2d::prelude::Position2D).
+    #[cfg(feature = "spatial2d")]
+    fn random_boundary_position(&self) -> rantz_spatial2d::prelude::Position2D {
+        let point = self.random_boundary();
+        rantz_spatial2d::prelude::Position2D::new(point.x, point.y)
+    }
+}
+
+/// A uniform point inside the unit disc, scaled by `radius`.
+pub(crate) fn in_disc(rng: &mut fastrand::Rng, radius: f32) -> Vec2 {
+    let distance = radius * rng.f32().sqrt();
+    on_circle(rng, distance)
+}
+
+pub(crate) fn on_circle(rng: &mut fastrand::Rng, radius: f32) -> Vec2 {
+    Vec2::from_angle(rng.f32() * TAU) * radius
+}
+
+/// A uniform point inside the triangle `a`, `b`, `c`.
+pub(crate) fn in_triangle<V>(rng: &mut fastrand::Rng, a: V, b: V, c: V) -> V
+where
+    V: Copy
+        + std::ops::Add<Output = V>
+        + std::ops::Sub<Output = V>
+        + std::ops::Mul<f32, Output = V>,
+{
+    let (mut u, mut v) = (rng.f32(), rng.f32());
+    // Fold the far half of the parallelogram back onto the triangle
+    if u + v > 1.0 {
+        u = 1.0 - u;
+        v = 1.0 - v;
+    }
+    a + (b - a) * u + (c - a) * v
+}
+
+/// A uniform point along the closed loop through `vertices`, `lengths` holds the running edge lengths.
+fn on_loop(rng: &mut fastrand::Rng, vertices: &[Vec2], lengths: &[f32]) -> Vec2 {
+    let edge = pick_cumulative(rng, lengths);
+    let start = vertices[edge];
+    let end = vertices[(edge + 1) % vertices.len()];
+    start.lerp(end, rng.f32())
+}
+
+fn loop_lengths(vertices: &[Vec2]) -> Vec<f32> {
+    cumulative(
+        (0..vertices.len()).map(|i| vertices[i].distance(vertices[(i + 1) % vertices.len()])),
+    )
+}
+
+impl RandomPoint2d for Circle {
+    fn random_interior_with(&self, rng: &mut fastrand::Rng) -> Vec2 {
+        in_disc(rng, self.radius)
+    }
+
+    fn random_boundary_with(&self, rng: &mut fastrand::Rng) -> Vec2 {
+        on_circle(rng, self.radius)
+    }
+}
+
+impl RandomPoint2d for Annulus {
+    fn random_interior_with(&self, rng: &mut fastrand::Rng) -> Vec2 {
+        let inner = self.inner_circle.radius.powi(2);
+        let outer = self.outer_circle.radius.powi(2);
+        let distance = (inner + (outer - inner) * rng.f32()).sqrt();
+        on_circle(rng, distance)
+    }
+
+    /// Either circle, weighted by circumference.
+    fn random_boundary_with(&self, rng: &mut fastrand::Rng) -> Vec2 {
+        let inner = self.inner_circle.radius;
+        let outer = self.outer_circle.radius;
+        if rng.f32() * (inner + outer) < inner {
+            on_circle(rng, inner)
+        } else {
+            on_circle(rng, outer)
+        }
+    }
+}
+
+impl RandomPoint2d for Rectangle {
+    fn random_interior_with(&self, rng: &mut fastrand::Rng) -> Vec2 {
+        let unit = Vec2::new(rng.f32(), rng.f32()) * 2.0 - Vec2::ONE;
+        unit * self.half_size
+    }
+
+    fn random_boundary_with(&self, rng: &mut fastrand::Rng) -> Vec2 {
+        let Vec2 { x, y } = self.half_size;
+        let corners = [
+            Vec2::new(-x, -y),
+            Vec2::new(x, -y),
+            Vec2::new(x, y),
+            Vec2::new(-x, y),
+        ];
+        on_loop(rng, &corners, &loop_lengths(&corners))
+    }
+}
+
+impl RandomPoint2d for Triangle2d {
+    fn random_interior_with(&self, rng: &mut fastrand::Rng) -> Vec2 {
+        let [a, b, c] = self.vertices;
+        in_triangle(rng, a, b, c)
+    }
+
+    fn random_boundary_with(&self, rng: &mut fastrand::Rng) -> Vec2 {
+        on_loop(rng, &self.vertices, &loop_lengths(&self.vertices))
+    }
+}
+
+/// Capsules run along the y axis, like Bevy's.
+impl RandomPoint2d for Capsule2d {
+    fn random_interior_with(&self, rng: &mut fastrand::Rng) -> Vec2 {
+        let body = 4.0 * self.radius * self.half_length;
+        let caps = PI * self.radius.powi(2);
+        if rng.f32() * (body + caps) < body {
+            let unit = Vec2::new(rng.f32(), rng.f32()) * 2.0 - Vec2::ONE;
+            return unit * Vec2::new(self.radius, self.half_length);
+        }
+
+        // The two caps together make a whole disc, each half slides to its own end
+        let point = in_disc(rng, self.radius);
+        point + Vec2::Y * self.half_length.copysign(point.y)
+    }
+
+    fn random_boundary_with(&self, rng: &mut fastrand::Rng) -> Vec2 {
+        let sides = 4.0 * self.half_length;
+        let caps = TAU * self.radius;
+        if rng.f32() * (sides + caps) < sides {
+            let x = if rng.bool() {
+                self.radius
+            } else {
+                -self.radius
+            };
+            return Vec2::new(x, (rng.f32() * 2.0 - 1.0) * self.half_length);
+        }
+
+        let point = on_circle(rng, self.radius);
+        point + Vec2::Y * self.half_length.copysign(point.y)
+    }
+}
+
+/// Samples any simple polygon, convex or concave, in either winding order.
+///
+/// The polygon is triangulated once when built, then points are picked from the triangles weighted by area.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use rantz_random::shapes::{PolygonSampler, RandomPoint2d};
+///
+/// // An L shaped arena
+/// let arena = PolygonSampler::new(&[
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(20.0, 0.0),
+///     Vec2::new(20.0, 10.0),
+///     Vec2::new(10.0, 10.0),
+///     Vec2::new(10.0, 20.0),
+///     Vec2::new(0.0, 20.0),
+/// ])
+/// .unwrap();
+///
+/// let spawn = arena.random_interior();
+/// assert!(spawn.x <= 10.0 || spawn.y <= 10.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolygonSampler {
+    vertices: Vec<Vec2>,
+    edge_lengths: Vec<f32>,
+    triangles: Vec<[Vec2; 3]>,
+    triangle_areas: Vec<f32>,
+}
+
+impl PolygonSampler {
+    /// Returns `None` for fewer than 3 vertices or no area.
+    ///
+    /// Polygons with holes or edges that cross each other aren't supported.
+    pub fn new(vertices: &[Vec2]) -> Option<Self> {
+        let triangles = triangulate(vertices)?;
+        let triangle_areas = cumulative(
+            triangles
+                .iter()
+                .map(|[a, b, c]| (*b - *a).perp_dot(*c - *a).abs() / 2.0),
+        );
+        if triangle_areas.last().copied().unwrap_or(0.0) <= 0.0 {
+            return None;
+        }
+
+        Some(Self {
+            vertices: vertices.to_vec(),
+            edge_lengths: loop_lengths(vertices),
+            triangles,
+            triangle_areas,
+        })
+    }
+
+    pub fn vertices(&self) -> &[Vec2] {
+        &self.vertices
+    }
+
+    /// The triangles the polygon was split into.
+    pub fn triangles(&self) -> &[[Vec2; 3]] {
+        &self.triangles
+    }
+
+    pub fn area(&self) -> f32 {
+        self.triangle_areas.last().copied().unwrap_or(0.0)
+    }
+}
+
+impl RandomPoint2d for PolygonSampler {
+    fn random_interior_with(&self, rng: &mut fastrand::Rng) -> Vec2 {
+        let [a, b, c] = self.triangles[pick_cumulative(rng, &self.triangle_areas)];
+        in_triangle(rng, a, b, c)
+    }
+
+    fn random_boundary_with(&self, rng: &mut fastrand::Rng) -> Vec2 {
+        on_loop(rng, &self.vertices, &self.edge_lengths)
+    }
+}
+
+/// Ear clipping, `None` if the polygon isn't simple.
+fn triangulate(vertices: &[Vec2]) -> Option<Vec<[Vec2; 3]>> {
+    if vertices.len() < 3 {
+        return None;
+    }
+
+    let signed_area: f32 = (0..vertices.len())
+        .map(|i| vertices[i].perp_dot(vertices[(i + 1) % vertices.len()]))
+        .sum();
+    let mut remaining = (0..vertices.len()).collect::<Vec<_>>();
+    if signed_area < 0.0 {
+        remaining.reverse();
+    }
+
+    let mut triangles = Vec::with_capacity(vertices.len() - 2);
+    while remaining.len() > 3 {
+        let count = remaining.len();
+        let ear = (0..count).find_map(|i| {
+            let prev = vertices[remaining[(i + count - 1) % count]];
+            let current = vertices[remaining[i]];
+            let next = vertices[remaining[(i + 1) % count]];
+            let turn = (current - prev).perp_dot(next - current);
+            if turn == 0.0 {
+                // Collinear vertices add nothing, drop them without a triangle
+                return Some((i, None));
+            }
+            if turn < 0.0 {
+                return None;
+            }
+
+            let blocked = remaining.iter().any(|&other| {
+                let point = vertices[other];
+                point != prev
+                    && point != current
+                    && point != next
+                    && contains(prev, current, next, point)
+            });
+            (!blocked).then_some((i, Some([prev, current, next])))
+        });
+
+        let (i, triangle) = ear?;
+        triangles.extend(triangle);
+        remaining.remove(i);
+    }
+
+    triangles.push([
+        vertices[remaining[0]],
+        vertices[remaining[1]],
+        vertices[remaining[2]],
+    ]);
+    Some(triangles)
+}
+
+/// Whether `point` is inside or on the edge of the counter clockwise triangle `a`, `b`, `c`.
+fn contains(a: Vec2, b: Vec2, c: Vec2, point: Vec2) -> bool {
+    (b - a).perp_dot(point - a) >= 0.0
+        && (c - b).perp_dot(point - b) >= 0.0
+        && (a - c).perp_dot(point - c) >= 0.0
+}
@@ -0,0 +1,354 @@
+use super::dim2::{in_disc, in_triangle, on_circle};
+use super::{cumulative, pick_cumulative};
+use crate::distributions::global_rng;
+use bevy::math::bounding::Aabb3d;
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology, VertexAttributeValues};
+use std::f32::consts::{PI, TAU};
+
+/// Uniform random points in 3D shapes.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use rantz_random::shapes::RandomPoint3d;
+///
+/// let emitter = Sphere::new(2.0);
+/// let spark = emitter.random_boundary();
+///
+/// assert!((spark.length() - 2.0).abs() < 1e-4);
+/// ```
+pub trait RandomPoint3d {
+    /// A point inside the shape, surface included, drawn using `rng`.
+    fn random_interior_with(&self, rng: &mut fastrand::Rng) -> Vec3;
+
+    /// A point on the surface of the shape, drawn using `rng`.
+    fn random_boundary_with(&self, rng: &mut fastrand::Rng) -> Vec3;
+
+    /// A point inside the shape, surface included.
+    fn random_interior(&self) -> Vec3 {
+        self.random_interior_with(&mut global_rng())
+    }
+
+    /// A point on the surface of the shape.
+    fn random_boundary(&self) -> Vec3 {
+        self.random_boundary_with(&mut global_rng())
+    }
+}
+
+/// A uniform point on the unit sphere.
+pub(crate) fn on_unit_sphere(rng: &mut fastrand::Rng) -> Vec3 {
+    // Archimedes, height on a sphere is uniform
+    let y = rng.f32() * 2.0 - 1.0;
+    let ring = on_circle(rng, (1.0 - y * y).max(0.0).sqrt());
+    Vec3::new(ring.x, y, ring.y)
+}
+
+fn in_ball(rng: &mut fastrand::Rng, radius: f32) -> Vec3 {
+    let distance = radius * rng.f32().cbrt();
+    on_unit_sphere(rng) * distance
+}
+
+fn in_box(rng: &mut fastrand::Rng, half_size: Vec3) -> Vec3 {
+    let unit = Vec3::new(rng.f32(), rng.f32(), rng.f32()) * 2.0 - Vec3::ONE;
+    unit * half_size
+}
+
+fn on_box(rng: &mut fastrand::Rng, half_size: Vec3) -> Vec3 {
+    let Vec3 { x, y, z } = half_size;
+    // Faces facing each axis, both sides share a weight
+    let axis = pick_cumulative(rng, &cumulative([y * z, x * z, x * y]));
+    let mut point = in_box(rng, half_size);
+    let side = if rng.bool() { 1.0 } else { -1.0 };
+    point[axis] = half_size[axis] * side;
+    point
+}
+
+/// Uniform inside the solid cylinder running along the y axis.
+fn in_cylinder(rng: &mut fastrand::Rng, radius: f32, half_height: f32) -> Vec3 {
+    let disc = in_disc(rng, radius);
+    Vec3::new(disc.x, (rng.f32() * 2.0 - 1.0) * half_height, disc.y)
+}
+
+/// Uniform on the curved side of the cylinder running along the y axis.
+fn on_cylinder_side(rng: &mut fastrand::Rng, radius: f32, half_height: f32) -> Vec3 {
+    let ring = on_circle(rng, radius);
+    Vec3::new(ring.x, (rng.f32() * 2.0 - 1.0) * half_height, ring.y)
+}
+
+impl RandomPoint3d for Sphere {
+    fn random_interior_with(&self, rng: &mut fastrand::Rng) -> Vec3 {
+        in_ball(rng, self.radius)
+    }
+
+    fn random_boundary_with(&self, rng: &mut fastrand::Rng) -> Vec3 {
+        on_unit_sphere(rng) * self.radius
+    }
+}
+
+impl RandomPoint3d for Cuboid {
+    fn random_interior_with(&self, rng: &mut fastrand::Rng) -> Vec3 {
+        in_box(rng, self.half_size)
+    }
+
+    fn random_boundary_with(&self, rng: &mut fastrand::Rng) -> Vec3 {
+        on_box(rng, self.half_size)
+    }
+}
+
+/// Unlike the primitives, bounding boxes aren't centered on the origin, so points land between `min` and `max`.
+impl RandomPoint3d for Aabb3d {
+    fn random_interior_with(&self, rng: &mut fastrand::Rng) -> Vec3 {
+        let (min, max) = (Vec3::from(self.min), Vec3::from(self.max));
+        (min + max) / 2.0 + in_box(rng, (max - min) / 2.0)
+    }
+
+    fn random_boundary_with(&self, rng: &mut fastrand::Rng) -> Vec3 {
+        let (min, max) = (Vec3::from(self.min), Vec3::from(self.max));
+        (min + max) / 2.0 + on_box(rng, (max - min) / 2.0)
+    }
+}
+
+/// Cylinders run along the y axis, like Bevy's.
+impl RandomPoint3d for Cylinder {
+    fn random_interior_with(&self, rng: &mut fastrand::Rng) -> Vec3 {
+        in_cylinder(rng, self.radius, self.half_height)
+    }
+
+    /// The curved side or either cap, weighted by area.
+    fn random_boundary_with(&self, rng: &mut fastrand::Rng) -> Vec3 {
+        let side = 2.0 * self.half_height;
+        let caps = self.radius;
+        if rng.f32() * (side + caps) < side {
+            return on_cylinder_side(rng, self.radius, self.half_height);
+        }
+
+        let disc = in_disc(rng, self.radius);
+        let y = if rng.bool() {
+            self.half_height
+        } else {
+            -self.half_height
+        };
+        Vec3::new(disc.x, y, disc.y)
+    }
+}
+
+/// Cones point up the y axis, with the tip at `height / 2` and the base at `-height / 2`, like Bevy's.
+impl RandomPoint3d for Cone {
+    fn random_interior_with(&self, rng: &mut fastrand::Rng) -> Vec3 {
+        // The cross section grows with the square of the distance from the tip
+        let depth = rng.f32().cbrt();
+        let disc = in_disc(rng, self.radius * depth);
+        Vec3::new(disc.x, self.height * (0.5 - depth), disc.y)
+    }
+
+    /// The sloped side or the base, weighted by area.
+    fn random_boundary_with(&self, rng: &mut fastrand::Rng) -> Vec3 {
+        let slant = self.radius.hypot(self.height);
+        if rng.f32() * (slant + self.radius) < self.radius {
+            let disc = in_disc(rng, self.radius);
+            return Vec3::new(disc.x, -self.height / 2.0, disc.y);
+        }
+
+        let depth = rng.f32().sqrt();
+        let ring = on_circle(rng, self.radius * depth);
+        Vec3::new(ring.x, self.height * (0.5 - depth), ring.y)
+    }
+}
+
+/// Capsules run along the y axis, like Bevy's.
+impl RandomPoint3d for Capsule3d {
+    fn random_interior_with(&self, rng: &mut fastrand::Rng) -> Vec3 {
+        let body = 2.0 * self.half_length;
+        let caps = 4.0 / 3.0 * self.radius;
+        if rng.f32() * (body + caps) < body {
+            return in_cylinder(rng, self.radius, self.half_length);
+        }
+
+        // The two caps together make a whole ball, each half slides to its own end
+        let point = in_ball(rng, self.radius);
+        point + Vec3::Y * self.half_length.copysign(point.y)
+    }
+
+    fn random_boundary_with(&self, rng: &mut fastrand::Rng) -> Vec3 {
+        let side = 4.0 * PI * self.radius * self.half_length;
+        let caps = 4.0 * PI * self.radius.powi(2);
+        if rng.f32() * (side + caps) < side {
+            return on_cylinder_side(rng, self.radius, self.half_length);
+        }
+
+        let point = on_unit_sphere(rng) * self.radius;
+        point + Vec3::Y * self.half_length.copysign(point.y)
+    }
+}
+
+/// The interior is the flat triangle itself, the boundary is its three edges.
+impl RandomPoint3d for Triangle3d {
+    fn random_interior_with(&self, rng: &mut fastrand::Rng) -> Vec3 {
+        let [a, b, c] = self.vertices;
+        in_triangle(rng, a, b, c)
+    }
+
+    fn random_boundary_with(&self, rng: &mut fastrand::Rng) -> Vec3 {
+        let [a, b, c] = self.vertices;
+        let edges = [(a, b), (b, c), (c, a)];
+        let lengths = cumulative(edges.iter().map(|(start, end)| start.distance(*end)));
+        let (start, end) = edges[pick_cumulative(rng, &lengths)];
+        start.lerp(end, rng.f32())
+    }
+}
+
+/// Half a ball, centered on the origin with its dome facing `normal`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hemisphere {
+    pub radius: f32,
+    pub normal: Dir3,
+}
+
+impl Default for Hemisphere {
+    fn default() -> Self {
+        Self {
+            radius: 0.5,
+            normal: Dir3::Y,
+        }
+    }
+}
+
+impl Hemisphere {
+    pub fn new(radius: f32, normal: Dir3) -> Self {
+        Self { radius, normal }
+    }
+
+    /// A point on the dome, more likely near the top in proportion to the cosine of its angle from `normal`.
+    ///
+    /// This is the spread of light off a matte surface, and makes emitters look natural.
+    pub fn random_cosine_weighted_with(&self, rng: &mut fastrand::Rng) -> Vec3 {
+        // Malley's method, a uniform disc projected up onto the dome
+        let disc = in_disc(rng, 1.0);
+        let up = (1.0 - disc.length_squared()).max(0.0).sqrt();
+        self.orient(Vec3::new(disc.x, up, disc.y) * self.radius)
+    }
+
+    /// [random_cosine_weighted_with](Self::random_cosine_weighted_with) using the global generator.
+    pub fn random_cosine_weighted(&self) -> Vec3 {
+        self.random_cosine_weighted_with(&mut global_rng())
+    }
+
+    /// Turns a point on the `+y` hemisphere to face `normal`.
+    fn orient(&self, point: Vec3) -> Vec3 {
+        Quat::from_rotation_arc(Vec3::Y, *self.normal) * point
+    }
+}
+
+impl RandomPoint3d for Hemisphere {
+    fn random_interior_with(&self, rng: &mut fastrand::Rng) -> Vec3 {
+        let point = in_ball(rng, self.radius);
+        self.orient(point.with_y(point.y.abs()))
+    }
+
+    /// A uniform point on the dome, the flat base isn't included.
+    fn random_boundary_with(&self, rng: &mut fastrand::Rng) -> Vec3 {
+        let point = on_unit_sphere(rng) * self.radius;
+        self.orient(point.with_y(point.y.abs()))
+    }
+}
+
+/// Samples the surface of a triangle mesh, picking triangles weighted by area.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use rantz_random::shapes::MeshSampler;
+///
+/// let mesh = Mesh::from(Cuboid::new(2.0, 2.0, 2.0));
+/// let surface = MeshSampler::from_mesh(&mesh).unwrap();
+///
+/// let point = surface.random_surface();
+/// assert!((point.abs().max_element() - 1.0).abs() < 1e-4);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeshSampler {
+    triangles: Vec<Triangle3d>,
+    areas: Vec<f32>,
+}
+
+impl MeshSampler {
+    /// Builds from a triangle list, every 3 `indices` into `positions` make a triangle.
+    ///
+    /// Returns `None` if an index is out of bounds or the mesh has no area.
+    pub fn new(positions: &[Vec3], indices: &[u32]) -> Option<Self> {
+        let triangles = indices
+            .chunks_exact(3)
+            .map(|triangle| {
+                Some(Triangle3d::new(
+                    *positions.get(triangle[0] as usize)?,
+                    *positions.get(triangle[1] as usize)?,
+                    *positions.get(triangle[2] as usize)?,
+                ))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Self::from_triangles(triangles)
+    }
+
+    /// Builds from a Bevy [Mesh], which must use [PrimitiveTopology::TriangleList] and have positions.
+    pub fn from_mesh(mesh: &Mesh) -> Option<Self> {
+        if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+            return None;
+        }
+        let Some(VertexAttributeValues::Float32x3(positions)) =
+            mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        else {
+            return None;
+        };
+        let positions = positions.iter().map(|p| Vec3::from(*p)).collect::<Vec<_>>();
+        let indices = match mesh.indices() {
+            Some(Indices::U16(indices)) => indices.iter().map(|i| *i as u32).collect(),
+            Some(Indices::U32(indices)) => indices.clone(),
+            None => (0..positions.len() as u32).collect::<Vec<_>>(),
+        };
+        Self::new(&positions, &indices)
+    }
+
+    fn from_triangles(triangles: Vec<Triangle3d>) -> Option<Self> {
+        let areas = cumulative(triangles.iter().map(|triangle| {
+            let [a, b, c] = triangle.vertices;
+            (b - a).cross(c - a).length() / 2.0
+        }));
+        if areas.last().copied().unwrap_or(0.0) <= 0.0 {
+            return None;
+        }
+        Some(Self { triangles, areas })
+    }
+
+    pub fn triangles(&self) -> &[Triangle3d] {
+        &self.triangles
+    }
+
+    pub fn area(&self) -> f32 {
+        self.areas.last().copied().unwrap_or(0.0)
+    }
+
+    /// A uniform point on the surface, drawn using `rng`.
+    pub fn random_surface_with(&self, rng: &mut fastrand::Rng) -> Vec3 {
+        self.triangles[pick_cumulative(rng, &self.areas)].random_interior_with(rng)
+    }
+
+    /// A uniform point on the surface.
+    pub fn random_surface(&self) -> Vec3 {
+        self.random_surface_with(&mut global_rng())
+    }
+}
+
+/// A uniform rotation over all of SO(3), Shoemake's method.
+pub(crate) fn uniform_quat(rng: &mut fastrand::Rng) -> Quat {
+    let (u1, u2, u3) = (rng.f32(), rng.f32() * TAU, rng.f32() * TAU);
+    let (a, b) = ((1.0 - u1).sqrt(), u1.sqrt());
+    Quat::from_xyzw(a * u2.sin(), a * u2.cos(), b * u3.sin(), b * u3.cos()).normalize()
+}
+
+/// A uniform direction within `half_angle` radians of `axis`.
+pub(crate) fn in_cone(rng: &mut fastrand::Rng, axis: Vec3, half_angle: f32) -> Vec3 {
+    // Like the whole sphere, height over a spherical cap is uniform
+    let min_height = half_angle.clamp(0.0, PI).cos();
+    let y = 1.0 - rng.f32() * (1.0 - min_height);
+    let ring = on_circle(rng, (1.0 - y * y).max(0.0).sqrt());
+    Quat::from_rotation_arc(Vec3::Y, axis) * Vec3::new(ring.x, y, ring.y)
+}
@@ -0,0 +1,46 @@
+//! Shapes
+//!
+//! Uniform random points inside and on the boundary of Bevy's math primitives.
+//! Primitives are centered on the origin, so offset the result to place it:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use rantz_random::shapes::RandomPoint2d;
+//!
+//! let arena_center = Vec2::new(100.0, 50.0);
+//! let spawn = arena_center + Circle::new(25.0).random_interior();
+//!
+//! assert!(spawn.distance(arena_center) <= 25.0);
+//! ```
+//!
+//! Shapes that need preparing first, like arbitrary polygons, have a sampler type that can be built once and reused.
+//! Every method has a `_with` version that takes an explicit [Rng](crate::Rng).
+
+mod dim2;
+mod dim3;
+
+pub use dim2::*;
+pub use dim3::*;
+
+pub(crate) use dim2::on_circle;
+pub(crate) use dim3::{in_cone, on_unit_sphere, uniform_quat};
+
+/// Picks an index with probability proportional to its share of the running total in `cumulative`.
+pub(crate) fn pick_cumulative(rng: &mut fastrand::Rng, cumulative: &[f32]) -> usize {
+    let total = cumulative.last().copied().unwrap_or(0.0);
+    let roll = rng.f32() * total;
+    cumulative
+        .partition_point(|sum| *sum <= roll)
+        .min(cumulative.len().saturating_sub(1))
+}
+
+/// Running totals of `weights`.
+pub(crate) fn cumulative(weights: impl IntoIterator<Item = f32>) -> Vec<f32> {
+    weights
+        .into_iter()
+        .scan(0.0, |sum, weight| {
+            *sum += weight;
+            Some(*sum)
+        })
+        .collect()
+}
@@ -1,4 +1,5 @@
 use crate::Shuffle;
+use std::collections::VecDeque;
 
 impl<T> Shuffle<T> for Vec<T>
 where
@@ -9,6 +10,47 @@ where
     }
 }
 
+impl<T> Shuffle<T> for VecDeque<T>
+where
+    T: Clone,
+{
+    fn shuffle(&mut self) {
+        fastrand::shuffle(self.make_contiguous());
+    }
+}
+
+#[cfg(feature = "indexmap")]
+mod indexmap {
+    use crate::Shuffle;
+    use indexmap::{IndexMap, IndexSet};
+    use std::hash::{BuildHasher, Hash};
+
+    impl<K, V, S> Shuffle<(K, V)> for IndexMap<K, V, S>
+    where
+        K: Clone + Eq + Hash,
+        V: Clone,
+        S: Clone + BuildHasher + Default,
+    {
+        fn shuffle(&mut self) {
+            for i in (1..self.len()).rev() {
+                self.swap_indices(i, fastrand::usize(0..=i));
+            }
+        }
+    }
+
+    impl<T, S> Shuffle<T> for IndexSet<T, S>
+    where
+        T: Clone + Eq + Hash,
+        S: Clone + BuildHasher + Default,
+    {
+        fn shuffle(&mut self) {
+            for i in (1..self.len()).rev() {
+                self.swap_indices(i, fastrand::usize(0..=i));
+            }
+        }
+    }
+}
+
 #[cfg(feature = "bevy")]
 mod bevy {
     use crate::Shuffle;
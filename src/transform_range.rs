@@ -0,0 +1,114 @@
+use crate::distributions::Distribution;
+use crate::shapes::uniform_quat;
+use bevy::prelude::*;
+
+/// TransformRange
+///
+/// Generates [Transform]s with the translation, rotation and scale each drawn from their own range.
+/// Every part starts at the identity and is configured with the `with_` methods:
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use rantz_random::{Distribution, RotationRange, TransformRange};
+///
+/// let debris = TransformRange::default()
+///     .with_translation(Vec3::new(-5.0, 0.0, -5.0), Vec3::new(5.0, 2.0, 5.0))
+///     .with_rotation(RotationRange::Any)
+///     .with_scale(0.5, 1.5);
+///
+/// let transform = debris.sample_random();
+/// assert!((0.5..=1.5).contains(&transform.scale.x));
+/// assert_eq!(transform.scale.x, transform.scale.z);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TransformRange {
+    translation: (Vec3, Vec3),
+    rotation: RotationRange,
+    scale: ScaleRange,
+}
+
+/// How a [TransformRange] picks rotations.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RotationRange {
+    /// Always [Quat::IDENTITY].
+    None,
+    /// Uniform over every orientation.
+    Any,
+    /// Spun around `axis` by an angle between `min` and `max` radians.
+    Around { axis: Dir3, min: f32, max: f32 },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ScaleRange {
+    Uniform(f32, f32),
+    PerAxis(Vec3, Vec3),
+}
+
+impl Default for TransformRange {
+    fn default() -> Self {
+        Self {
+            translation: (Vec3::ZERO, Vec3::ZERO),
+            rotation: RotationRange::None,
+            scale: ScaleRange::Uniform(1.0, 1.0),
+        }
+    }
+}
+
+impl TransformRange {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Translations inside the box between `min` and `max`.
+    pub fn with_translation(mut self, min: Vec3, max: Vec3) -> Self {
+        self.translation = (min, max);
+        self
+    }
+
+    pub fn with_rotation(mut self, rotation: RotationRange) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// The same scale on every axis, between `min` and `max`.
+    pub fn with_scale(mut self, min: f32, max: f32) -> Self {
+        self.scale = ScaleRange::Uniform(min, max);
+        self
+    }
+
+    /// Each axis scaled independently, between `min` and `max`.
+    pub fn with_scale_per_axis(mut self, min: Vec3, max: Vec3) -> Self {
+        self.scale = ScaleRange::PerAxis(min, max);
+        self
+    }
+}
+
+impl Distribution<Transform> for TransformRange {
+    fn sample(&self, rng: &mut fastrand::Rng) -> Transform {
+        let (min, max) = self.translation;
+        let translation = min + (max - min) * unit_vec3(rng);
+
+        let rotation = match self.rotation {
+            RotationRange::None => Quat::IDENTITY,
+            RotationRange::Any => uniform_quat(rng),
+            RotationRange::Around { axis, min, max } => {
+                Quat::from_axis_angle(*axis, min + (max - min) * rng.f32())
+            }
+        };
+
+        let scale = match self.scale {
+            ScaleRange::Uniform(min, max) => Vec3::splat(min + (max - min) * rng.f32()),
+            ScaleRange::PerAxis(min, max) => min + (max - min) * unit_vec3(rng),
+        };
+
+        Transform {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+}
+
+fn unit_vec3(rng: &mut fastrand::Rng) -> Vec3 {
+    Vec3::new(rng.f32(), rng.f32(), rng.f32())
+}
@@ -0,0 +1,373 @@
+use crate::distributions::{global_rng, standard_normal, Distribution, Pareto};
+use crate::shapes::{in_cone, on_circle, on_unit_sphere};
+use bevy::prelude::*;
+
+/// Brownian motion in 2D, every step a normally distributed offset on each axis.
+///
+/// An endless iterator that starts with `start` and then yields each position as it's reached.
+/// For a fixed time step `dt`, a standard deviation of `sqrt(dt)` times the diffusion rate keeps the spread independent of `dt`.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use rantz_random::walk::BrownianMotion2d;
+///
+/// let dust: Vec<Vec2> = BrownianMotion2d::new(Vec2::ZERO, 0.1)
+///     .with_drift(Vec2::new(0.05, 0.0))
+///     .take(100)
+///     .collect();
+/// ```
+#[derive(Clone, Debug)]
+pub struct BrownianMotion2d {
+    position: Vec2,
+    std_dev: f32,
+    drift: Vec2,
+    rng: fastrand::Rng,
+}
+
+impl BrownianMotion2d {
+    /// Steps with a standard deviation of `std_dev` on each axis.
+    pub fn new(start: Vec2, std_dev: f32) -> Self {
+        Self {
+            position: start,
+            std_dev,
+            drift: Vec2::ZERO,
+            rng: global_rng(),
+        }
+    }
+
+    /// Adds `drift` to every step, like a current or wind.
+    pub fn with_drift(mut self, drift: Vec2) -> Self {
+        self.drift = drift;
+        self
+    }
+
+    /// Draws the walk from a generator forked from `rng`, so it can be replayed from a seed.
+    pub fn with_rng(mut self, rng: &mut fastrand::Rng) -> Self {
+        self.rng = rng.fork();
+        self
+    }
+
+    /// The position the walk will yield next.
+    pub fn position(&self) -> Vec2 {
+        self.position
+    }
+}
+
+impl Iterator for BrownianMotion2d {
+    type Item = Vec2;
+
+    fn next(&mut self) -> Option<Vec2> {
+        let current = self.position;
+        let offset = Vec2::new(
+            standard_normal(&mut self.rng) as f32,
+            standard_normal(&mut self.rng) as f32,
+        );
+        self.position += self.drift + offset * self.std_dev;
+        Some(current)
+    }
+}
+
+/// Brownian motion in 3D, like [BrownianMotion2d].
+#[derive(Clone, Debug)]
+pub struct BrownianMotion3d {
+    position: Vec3,
+    std_dev: f32,
+    drift: Vec3,
+    rng: fastrand::Rng,
+}
+
+impl BrownianMotion3d {
+    /// Steps with a standard deviation of `std_dev` on each axis.
+    pub fn new(start: Vec3, std_dev: f32) -> Self {
+        Self {
+            position: start,
+            std_dev,
+            drift: Vec3::ZERO,
+            rng: global_rng(),
+        }
+    }
+
+    /// Adds `drift` to every step, like a current or wind.
+    pub fn with_drift(mut self, drift: Vec3) -> Self {
+        self.drift = drift;
+        self
+    }
+
+    /// Draws the walk from a generator forked from `rng`, so it can be replayed from a seed.
+    pub fn with_rng(mut self, rng: &mut fastrand::Rng) -> Self {
+        self.rng = rng.fork();
+        self
+    }
+
+    /// The position the walk will yield next.
+    pub fn position(&self) -> Vec3 {
+        self.position
+    }
+}
+
+impl Iterator for BrownianMotion3d {
+    type Item = Vec3;
+
+    fn next(&mut self) -> Option<Vec3> {
+        let current = self.position;
+        let offset = Vec3::new(
+            standard_normal(&mut self.rng) as f32,
+            standard_normal(&mut self.rng) as f32,
+            standard_normal(&mut self.rng) as f32,
+        );
+        self.position += self.drift + offset * self.std_dev;
+        Some(current)
+    }
+}
+
+/// A correlated random walk in 2D, fixed length steps that turn a little from the last heading.
+///
+/// Wanders like an animal grazing rather than jittering in place.
+/// Starts facing a random direction, unless given one with [with_heading](Self::with_heading).
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use rantz_random::walk::CorrelatedWalk2d;
+/// use std::f32::consts::PI;
+///
+/// let path: Vec<Vec2> = CorrelatedWalk2d::new(Vec2::ZERO, 1.0, PI / 8.0)
+///     .with_heading(Dir2::X)
+///     .take(50)
+///     .collect();
+///
+/// for pair in path.windows(2) {
+///     assert!((pair[0].distance(pair[1]) - 1.0).abs() < 1e-4);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CorrelatedWalk2d {
+    position: Vec2,
+    heading: Vec2,
+    step_length: f32,
+    max_turn: f32,
+    rng: fastrand::Rng,
+}
+
+impl CorrelatedWalk2d {
+    /// Steps of `step_length`, each turning up to `max_turn` radians either way.
+    pub fn new(start: Vec2, step_length: f32, max_turn: f32) -> Self {
+        let mut rng = global_rng();
+        Self {
+            position: start,
+            heading: on_circle(&mut rng, 1.0),
+            step_length,
+            max_turn,
+            rng,
+        }
+    }
+
+    pub fn with_heading(mut self, heading: Dir2) -> Self {
+        self.heading = *heading;
+        self
+    }
+
+    /// Draws the walk from a generator forked from `rng`, so it can be replayed from a seed.
+    ///
+    /// Also picks a new starting heading, call [with_heading](Self::with_heading) after this to keep one.
+    pub fn with_rng(mut self, rng: &mut fastrand::Rng) -> Self {
+        self.rng = rng.fork();
+        self.heading = on_circle(&mut self.rng, 1.0);
+        self
+    }
+
+    /// The position the walk will yield next.
+    pub fn position(&self) -> Vec2 {
+        self.position
+    }
+
+    /// The direction of the next step, before it turns.
+    pub fn heading(&self) -> Dir2 {
+        Dir2::new_unchecked(self.heading)
+    }
+}
+
+impl Iterator for CorrelatedWalk2d {
+    type Item = Vec2;
+
+    fn next(&mut self) -> Option<Vec2> {
+        let current = self.position;
+        let turn = (self.rng.f32() * 2.0 - 1.0) * self.max_turn;
+        self.heading = Vec2::from_angle(turn).rotate(self.heading).normalize();
+        self.position += self.heading * self.step_length;
+        Some(current)
+    }
+}
+
+/// A correlated random walk in 3D, like [CorrelatedWalk2d] with each step turning within a cone.
+#[derive(Clone, Debug)]
+pub struct CorrelatedWalk3d {
+    position: Vec3,
+    heading: Vec3,
+    step_length: f32,
+    max_turn: f32,
+    rng: fastrand::Rng,
+}
+
+impl CorrelatedWalk3d {
+    /// Steps of `step_length`, each within `max_turn` radians of the last.
+    pub fn new(start: Vec3, step_length: f32, max_turn: f32) -> Self {
+        let mut rng = global_rng();
+        Self {
+            position: start,
+            heading: on_unit_sphere(&mut rng),
+            step_length,
+            max_turn,
+            rng,
+        }
+    }
+
+    pub fn with_heading(mut self, heading: Dir3) -> Self {
+        self.heading = *heading;
+        self
+    }
+
+    /// Draws the walk from a generator forked from `rng`, so it can be replayed from a seed.
+    ///
+    /// Also picks a new starting heading, call [with_heading](Self::with_heading) after this to keep one.
+    pub fn with_rng(mut self, rng: &mut fastrand::Rng) -> Self {
+        self.rng = rng.fork();
+        self.heading = on_unit_sphere(&mut self.rng);
+        self
+    }
+
+    /// The position the walk will yield next.
+    pub fn position(&self) -> Vec3 {
+        self.position
+    }
+
+    /// The direction of the next step, before it turns.
+    pub fn heading(&self) -> Dir3 {
+        Dir3::new_unchecked(self.heading)
+    }
+}
+
+impl Iterator for CorrelatedWalk3d {
+    type Item = Vec3;
+
+    fn next(&mut self) -> Option<Vec3> {
+        let current = self.position;
+        self.heading = in_cone(&mut self.rng, self.heading, self.max_turn).normalize();
+        self.position += self.heading * self.step_length;
+        Some(current)
+    }
+}
+
+/// A Lévy flight in 2D, steps in random directions with heavy tailed lengths.
+///
+/// Mostly short steps broken up by rare long jumps, a good fit for foraging or searching AI.
+/// Step lengths follow a [Pareto] distribution with minimum `min_step` and tail index `alpha`,
+/// the smaller `alpha` the more often long jumps happen.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use rantz_random::walk::LevyFlight2d;
+///
+/// let search = LevyFlight2d::new(Vec2::ZERO, 0.5, 1.5)
+///     .unwrap()
+///     .with_max_step(20.0);
+///
+/// for point in search.take(100) {
+///     assert!(point.is_finite());
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LevyFlight2d {
+    position: Vec2,
+    lengths: Pareto<f32>,
+    max_step: f32,
+    rng: fastrand::Rng,
+}
+
+impl LevyFlight2d {
+    /// `None` unless `min_step` and `alpha` are finite and positive.
+    pub fn new(start: Vec2, min_step: f32, alpha: f32) -> Option<Self> {
+        Some(Self {
+            position: start,
+            lengths: Pareto::new(min_step, alpha)?,
+            max_step: f32::INFINITY,
+            rng: global_rng(),
+        })
+    }
+
+    /// Caps every step at `max_step`.
+    pub fn with_max_step(mut self, max_step: f32) -> Self {
+        self.max_step = max_step;
+        self
+    }
+
+    /// Draws the walk from a generator forked from `rng`, so it can be replayed from a seed.
+    pub fn with_rng(mut self, rng: &mut fastrand::Rng) -> Self {
+        self.rng = rng.fork();
+        self
+    }
+
+    /// The position the walk will yield next.
+    pub fn position(&self) -> Vec2 {
+        self.position
+    }
+}
+
+impl Iterator for LevyFlight2d {
+    type Item = Vec2;
+
+    fn next(&mut self) -> Option<Vec2> {
+        let current = self.position;
+        let length = self.lengths.sample(&mut self.rng).min(self.max_step);
+        self.position += on_circle(&mut self.rng, length);
+        Some(current)
+    }
+}
+
+/// A Lévy flight in 3D, like [LevyFlight2d].
+#[derive(Clone, Debug)]
+pub struct LevyFlight3d {
+    position: Vec3,
+    lengths: Pareto<f32>,
+    max_step: f32,
+    rng: fastrand::Rng,
+}
+
+impl LevyFlight3d {
+    /// `None` unless `min_step` and `alpha` are finite and positive.
+    pub fn new(start: Vec3, min_step: f32, alpha: f32) -> Option<Self> {
+        Some(Self {
+            position: start,
+            lengths: Pareto::new(min_step, alpha)?,
+            max_step: f32::INFINITY,
+            rng: global_rng(),
+        })
+    }
+
+    /// Caps every step at `max_step`.
+    pub fn with_max_step(mut self, max_step: f32) -> Self {
+        self.max_step = max_step;
+        self
+    }
+
+    /// Draws the walk from a generator forked from `rng`, so it can be replayed from a seed.
+    pub fn with_rng(mut self, rng: &mut fastrand::Rng) -> Self {
+        self.rng = rng.fork();
+        self
+    }
+
+    /// The position the walk will yield next.
+    pub fn position(&self) -> Vec3 {
+        self.position
+    }
+}
+
+impl Iterator for LevyFlight3d {
+    type Item = Vec3;
+
+    fn next(&mut self) -> Option<Vec3> {
+        let current = self.position;
+        let length = self.lengths.sample(&mut self.rng).min(self.max_step);
+        self.position += on_unit_sphere(&mut self.rng) * length;
+        Some(current)
+    }
+}
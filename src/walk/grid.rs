@@ -0,0 +1,230 @@
+use crate::distributions::global_rng;
+use crate::WeightedTable;
+use bevy::prelude::*;
+
+/// Which cells a grid walk can step to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GridNeighbours {
+    /// Across a face, 4 neighbours in 2D and 6 in 3D.
+    #[default]
+    Orthogonal,
+    /// Across a face, edge or corner, 8 neighbours in 2D and 26 in 3D.
+    WithDiagonals,
+}
+
+/// A random walk over the cells of a 2D grid, one step to a neighbouring cell at a time.
+///
+/// An endless iterator that starts with `start` and then yields each cell as it's reached,
+/// so a walk of `n` cells is `take(n)`:
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use rantz_random::walk::{GridNeighbours, GridWalk2d};
+/// use std::collections::HashSet;
+///
+/// let cave: HashSet<IVec2> = GridWalk2d::new(IVec2::ZERO, GridNeighbours::Orthogonal)
+///     .with_bounds(IRect::new(-20, -20, 20, 20))
+///     .take(500)
+///     .collect();
+/// ```
+#[derive(Clone, Debug)]
+pub struct GridWalk2d {
+    position: IVec2,
+    steps: Vec<IVec2>,
+    cumulative: Vec<u32>,
+    bounds: Option<IRect>,
+    rng: fastrand::Rng,
+}
+
+impl GridWalk2d {
+    /// Each neighbour equally likely.
+    pub fn new(start: IVec2, neighbours: GridNeighbours) -> Self {
+        let mut steps = vec![IVec2::X, IVec2::Y, IVec2::NEG_X, IVec2::NEG_Y];
+        if neighbours == GridNeighbours::WithDiagonals {
+            steps.extend([
+                IVec2::ONE,
+                IVec2::new(-1, 1),
+                IVec2::NEG_ONE,
+                IVec2::new(1, -1),
+            ]);
+        }
+        let weights = vec![1; steps.len()];
+        Self::from_steps(start, steps, weights)
+    }
+
+    /// Each step drawn from `steps` in proportion to its weight, or `None` if nothing has any weight.
+    ///
+    /// Steps don't have to be to a neighbour, so a knight's move walk is possible.
+    pub fn weighted(start: IVec2, steps: &WeightedTable<IVec2>) -> Option<Self> {
+        if steps.total_weight == 0 {
+            return None;
+        }
+        Some(Self::from_steps(
+            start,
+            steps.values.clone(),
+            steps.weights.clone(),
+        ))
+    }
+
+    /// Each step towards a point of the compass, drawn in proportion to its weight,
+    /// or `None` if nothing has any weight.
+    #[cfg(feature = "spatial2d")]
+    pub fn compass(
+        start: IVec2,
+        directions: &WeightedTable<rantz_spatial2d::prelude::CompassRose>,
+    ) -> Option<Self> {
+        if directions.total_weight == 0 {
+            return None;
+        }
+        let steps = directions
+            .values
+            .iter()
+            .map(|direction| Vec2::from(*direction).round().as_ivec2())
+            .collect();
+        Some(Self::from_steps(start, steps, directions.weights.clone()))
+    }
+
+    fn from_steps(start: IVec2, steps: Vec<IVec2>, weights: Vec<u32>) -> Self {
+        Self {
+            position: start,
+            steps,
+            cumulative: cumulative(&weights),
+            bounds: None,
+            rng: global_rng(),
+        }
+    }
+
+    /// Keeps the walk inside `bounds`, edges included, steps that would leave it stop at the edge.
+    pub fn with_bounds(mut self, bounds: IRect) -> Self {
+        self.position = self.position.clamp(bounds.min, bounds.max);
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Draws the walk from a generator forked from `rng`, so it can be replayed from a seed.
+    pub fn with_rng(mut self, rng: &mut fastrand::Rng) -> Self {
+        self.rng = rng.fork();
+        self
+    }
+
+    /// The cell the walk will yield next.
+    pub fn position(&self) -> IVec2 {
+        self.position
+    }
+}
+
+impl Iterator for GridWalk2d {
+    type Item = IVec2;
+
+    fn next(&mut self) -> Option<IVec2> {
+        let current = self.position;
+        let step = self.steps[pick(&mut self.rng, &self.cumulative)];
+        self.position = current.saturating_add(step);
+        if let Some(bounds) = self.bounds {
+            self.position = self.position.clamp(bounds.min, bounds.max);
+        }
+        Some(current)
+    }
+}
+
+/// A random walk over the cells of a 3D grid, like [GridWalk2d].
+#[derive(Clone, Debug)]
+pub struct GridWalk3d {
+    position: IVec3,
+    steps: Vec<IVec3>,
+    cumulative: Vec<u32>,
+    bounds: Option<(IVec3, IVec3)>,
+    rng: fastrand::Rng,
+}
+
+impl GridWalk3d {
+    /// Each neighbour equally likely.
+    pub fn new(start: IVec3, neighbours: GridNeighbours) -> Self {
+        let mut steps = Vec::new();
+        for z in -1..=1 {
+            for y in -1..=1 {
+                for x in -1..=1 {
+                    let step = IVec3::new(x, y, z);
+                    let axes = step.abs().element_sum();
+                    if axes == 1 || (axes > 1 && neighbours == GridNeighbours::WithDiagonals) {
+                        steps.push(step);
+                    }
+                }
+            }
+        }
+        let weights = vec![1; steps.len()];
+        Self::from_steps(start, steps, weights)
+    }
+
+    /// Each step drawn from `steps` in proportion to its weight, or `None` if nothing has any weight.
+    pub fn weighted(start: IVec3, steps: &WeightedTable<IVec3>) -> Option<Self> {
+        if steps.total_weight == 0 {
+            return None;
+        }
+        Some(Self::from_steps(
+            start,
+            steps.values.clone(),
+            steps.weights.clone(),
+        ))
+    }
+
+    fn from_steps(start: IVec3, steps: Vec<IVec3>, weights: Vec<u32>) -> Self {
+        Self {
+            position: start,
+            steps,
+            cumulative: cumulative(&weights),
+            bounds: None,
+            rng: global_rng(),
+        }
+    }
+
+    /// Keeps the walk inside the box between `min` and `max`, both included.
+    pub fn with_bounds(mut self, min: IVec3, max: IVec3) -> Self {
+        self.position = self.position.clamp(min, max);
+        self.bounds = Some((min, max));
+        self
+    }
+
+    /// Draws the walk from a generator forked from `rng`, so it can be replayed from a seed.
+    pub fn with_rng(mut self, rng: &mut fastrand::Rng) -> Self {
+        self.rng = rng.fork();
+        self
+    }
+
+    /// The cell the walk will yield next.
+    pub fn position(&self) -> IVec3 {
+        self.position
+    }
+}
+
+impl Iterator for GridWalk3d {
+    type Item = IVec3;
+
+    fn next(&mut self) -> Option<IVec3> {
+        let current = self.position;
+        let step = self.steps[pick(&mut self.rng, &self.cumulative)];
+        self.position = current.saturating_add(step);
+        if let Some((min, max)) = self.bounds {
+            self.position = self.position.clamp(min, max);
+        }
+        Some(current)
+    }
+}
+
+/// Running totals of `weights`.
+fn cumulative(weights: &[u32]) -> Vec<u32> {
+    weights
+        .iter()
+        .scan(0u32, |sum, weight| {
+            *sum += weight;
+            Some(*sum)
+        })
+        .collect()
+}
+
+/// Picks an index with probability proportional to its share of the running total in `cumulative`.
+fn pick(rng: &mut fastrand::Rng, cumulative: &[u32]) -> usize {
+    let total = cumulative.last().copied().unwrap_or(0);
+    let roll = rng.u32(0..total);
+    cumulative.partition_point(|sum| *sum <= roll)
+}
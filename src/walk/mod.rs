@@ -0,0 +1,34 @@
+//! Walk
+//!
+//! Random walks, endless iterators of positions that each move a random step from the last.
+//! Grid walks carve caves and rivers out of tile maps, continuous ones move particles and wandering AI:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use rantz_random::walk::{GridNeighbours, GridWalk2d};
+//! use rantz_random::Rng;
+//!
+//! let mut rng = Rng::with_seed(5);
+//! let tunnel: Vec<IVec2> = GridWalk2d::new(IVec2::ZERO, GridNeighbours::Orthogonal)
+//!     .with_rng(&mut rng)
+//!     .take(200)
+//!     .collect();
+//!
+//! for pair in tunnel.windows(2) {
+//!     assert_eq!((pair[1] - pair[0]).abs().element_sum(), 1);
+//! }
+//! ```
+//!
+//! - [GridWalk2d] and [GridWalk3d] step between grid cells, uniformly or weighted by direction
+//! - [BrownianMotion2d] and [BrownianMotion3d] jitter by normally distributed offsets
+//! - [CorrelatedWalk2d] and [CorrelatedWalk3d] keep roughly the same heading from step to step
+//! - [LevyFlight2d] and [LevyFlight3d] mix short steps with rare long jumps
+//!
+//! Every walk starts with its starting position and draws from the global generator,
+//! `with_rng` forks an explicit [Rng](crate::Rng) instead.
+
+mod continuous;
+mod grid;
+
+pub use continuous::*;
+pub use grid::*;
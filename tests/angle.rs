@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod tests {
+    use rantz_random::angle::AngleArc;
+    use rantz_random::{Distribution, Rng};
+    use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+    fn degrees(value: f32) -> f32 {
+        value.to_radians()
+    }
+
+    #[test]
+    fn counterclockwise_arcs_cross_zero() {
+        let arc = AngleArc::counterclockwise(degrees(350.0), degrees(10.0));
+        let mut rng = Rng::with_seed(1);
+
+        assert!((arc.sweep() - degrees(20.0)).abs() < 1e-5);
+        for _ in 0..1000 {
+            let angle = arc.sample(&mut rng);
+            assert!((degrees(350.0)..=degrees(370.0)).contains(&angle));
+            assert!(arc.contains(angle));
+        }
+    }
+
+    #[test]
+    fn clockwise_arcs_turn_the_other_way() {
+        let arc = AngleArc::clockwise(degrees(10.0), degrees(350.0));
+        let mut rng = Rng::with_seed(2);
+
+        assert!((arc.sweep() + degrees(20.0)).abs() < 1e-5);
+        for _ in 0..1000 {
+            let angle = arc.sample(&mut rng);
+            assert!((degrees(-10.0)..=degrees(10.0)).contains(&angle));
+        }
+        assert!(arc.contains(0.0));
+        assert!(!arc.contains(PI));
+    }
+
+    #[test]
+    fn shortest_arcs_pick_the_shorter_way() {
+        assert!(
+            (AngleArc::shortest(degrees(10.0), degrees(350.0)).sweep() + degrees(20.0)).abs()
+                < 1e-5
+        );
+        assert!(
+            (AngleArc::shortest(degrees(350.0), degrees(10.0)).sweep() - degrees(20.0)).abs()
+                < 1e-5
+        );
+        assert_eq!(
+            AngleArc::shortest(0.0, std::f64::consts::PI).sweep(),
+            std::f64::consts::PI
+        );
+        assert!(AngleArc::shortest(0.0, TAU).sweep().abs() < 1e-5);
+    }
+
+    #[test]
+    fn whole_turns_are_the_full_circle() {
+        let arc = AngleArc::counterclockwise(0.0, TAU);
+
+        assert_eq!(arc.sweep(), TAU);
+        assert!(arc.contains(PI));
+        assert_eq!(AngleArc::<f32>::full().sweep(), TAU);
+        assert_eq!(AngleArc::counterclockwise(1.0, 1.0).sweep(), 0.0);
+    }
+
+    #[test]
+    fn arcs_around_a_center() {
+        let arc = AngleArc::around(FRAC_PI_2, degrees(15.0));
+        let mut rng = Rng::with_seed(3);
+
+        for _ in 0..1000 {
+            assert!((arc.sample(&mut rng) - FRAC_PI_2).abs() <= degrees(15.0) + 1e-5);
+        }
+        assert!(arc.contains(FRAC_PI_2 + TAU));
+        assert!(!arc.contains(0.0));
+        assert_eq!(AngleArc::around(0.0, 10.0f32).sweep(), TAU);
+    }
+
+    #[cfg(feature = "spatial2d")]
+    #[test]
+    fn degree_ranges_cross_zero() {
+        use rantz_random::RandomRange;
+        use rantz_spatial2d::prelude::*;
+
+        rantz_random::seed(4);
+        for _ in 0..1000 {
+            let angle = Degrees::random_range(Degrees::new(350.0), Degrees::new(10.0)).to_f32();
+            assert!((350.0..=370.0).contains(&angle), "{angle}");
+        }
+
+        let turn = Radians::random_range(Radians::new(0.0), Radians::new(TAU)).to_f32();
+        assert!((0.0..=TAU).contains(&turn));
+    }
+
+    #[cfg(feature = "spatial2d")]
+    #[test]
+    fn degree_ranges_agree_with_random_range() {
+        use rantz_random::{RandomIn, RandomRange};
+        use rantz_spatial2d::prelude::*;
+
+        let (from, to) = (Degrees::new(350.0), Degrees::new(10.0));
+        let mut spans = [(f32::MAX, f32::MIN); 2];
+        for _ in 0..1000 {
+            let angles = [
+                Degrees::random_in(from..=to).to_f32(),
+                Degrees::random_range(from, to).to_f32(),
+            ];
+            for (angle, span) in angles.into_iter().zip(&mut spans) {
+                assert!((350.0..=370.0).contains(&angle), "{angle}");
+                *span = (span.0.min(angle), span.1.max(angle));
+            }
+        }
+        for (low, high) in spans {
+            assert!(low < 355.0 && high > 365.0, "{low}..{high}");
+        }
+
+        for _ in 0..1000 {
+            let angle = Radians::random_in(Radians::new(TAU - 0.1)..Radians::new(0.1)).to_f32();
+            assert!((TAU - 0.1..TAU + 0.1).contains(&angle), "{angle}");
+        }
+    }
+}
@@ -0,0 +1,148 @@
+#![cfg(feature = "bevy")]
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use rantz_random::{Random, RandomIn, RandomRange, RandomVector};
+
+    /// Random bounds anywhere in `i32::MIN..=i32::MAX`, ordered per component
+    fn ivec_bounds() -> (IVec3, IVec3) {
+        let a = IVec3::random();
+        let b = IVec3::random();
+        (a.min(b), a.max(b))
+    }
+
+    #[test]
+    fn ivec_random_range_stays_within_arbitrary_bounds() {
+        rantz_random::seed(7);
+        for _ in 0..10000 {
+            let (min, max) = ivec_bounds();
+            let v = IVec3::random_range(min, max);
+
+            assert!(v.cmpge(min).all() && v.cmple(max).all());
+        }
+    }
+
+    #[test]
+    fn ivec_random_range_handles_the_full_i32_span() {
+        let min = IVec4::splat(i32::MIN);
+        let max = IVec4::splat(i32::MAX);
+        let mut signs = [false; 2];
+        for _ in 0..1000 {
+            let v = IVec4::random_range(min, max);
+            signs[0] |= v.x < 0;
+            signs[1] |= v.x > 0;
+            let _ = IVec2::random_range(min.xy(), max.xy());
+        }
+
+        assert_eq!(signs, [true, true]);
+    }
+
+    #[test]
+    fn uvec_random_range_handles_the_full_u32_span() {
+        for _ in 0..1000 {
+            let _ = UVec3::random_range(UVec3::ZERO, UVec3::MAX);
+        }
+    }
+
+    #[test]
+    fn integer_vector_ranges_include_max() {
+        let mut seen = [false; 2];
+        for _ in 0..1000 {
+            let v = UVec2::random_range(UVec2::new(0, 5), UVec2::new(1, 5));
+            seen[v.x as usize] = true;
+            assert_eq!(v.y, 5);
+
+            let v = IVec2::random_range(IVec2::new(i32::MAX - 1, -3), IVec2::new(i32::MAX, -3));
+            assert!(v.x >= i32::MAX - 1);
+            assert_eq!(v.y, -3);
+        }
+
+        assert_eq!(seen, [true, true]);
+    }
+
+    #[test]
+    fn integer_vector_ranges_are_exact_beyond_f32_precision() {
+        // Spans above 2^24 used to go through f32 and could only produce multiples of a power of two
+        let mut odd = false;
+        for _ in 0..100 {
+            let v = UVec2::random_range(UVec2::ZERO, UVec2::splat(1 << 30));
+            odd |= v.x % 2 == 1 || v.y % 2 == 1;
+        }
+
+        assert!(odd);
+    }
+
+    #[test]
+    fn integer_vector_ranges_are_uniform() {
+        let mut counts = [0; 4];
+        for _ in 0..8000 {
+            let v = IVec2::random_range(IVec2::splat(-2), IVec2::splat(1));
+            counts[(v.x + 2) as usize] += 1;
+        }
+
+        assert!(counts.iter().all(|c| (1800..2200).contains(c)));
+    }
+
+    #[test]
+    fn integer_vectors_support_exclusive_bounds() {
+        rantz_random::seed(11);
+        for _ in 0..10000 {
+            let (min, max) = ivec_bounds();
+            if min.cmpeq(max).any() {
+                continue;
+            }
+            let v = IVec3::random_in(min..max);
+
+            assert!(v.cmpge(min).all() && v.cmplt(max).all());
+        }
+    }
+
+    #[test]
+    fn random_float_vectors_are_in_the_unit_range() {
+        for _ in 0..1000 {
+            let v = Vec4::random();
+            assert!(v.cmpge(Vec4::ZERO).all() && v.cmplt(Vec4::ONE).all());
+
+            let v = Vec2::random();
+            assert!(v.cmpge(Vec2::ZERO).all() && v.cmplt(Vec2::ONE).all());
+        }
+    }
+
+    #[test]
+    fn random_in_aabb_includes_both_corners() {
+        for _ in 0..1000 {
+            let min = Vec3::new(-10.0, 0.0, 5.0);
+            let max = Vec3::new(-5.0, 0.0, 6.0);
+            let v = Vec3::random_in_aabb(min, max);
+
+            assert!(v.cmpge(min).all() && v.cmple(max).all());
+            assert_eq!(v.y, 0.0);
+        }
+    }
+
+    #[test]
+    fn random_in_unit_cube_is_centered() {
+        let mut sum = Vec3::ZERO;
+        for _ in 0..10000 {
+            let v = Vec3::random_in_unit_cube();
+            assert!(v.abs().cmple(Vec3::ONE).all());
+            sum += v;
+        }
+
+        assert!((sum / 10000.0).abs().cmplt(Vec3::splat(0.05)).all());
+    }
+
+    #[test]
+    fn random_finite_covers_the_whole_range() {
+        let mut signs = [false; 2];
+        for _ in 0..1000 {
+            let v = Vec2::random_finite();
+            assert!(v.is_finite());
+            signs[0] |= v.x < -1e30;
+            signs[1] |= v.x > 1e30;
+        }
+
+        assert_eq!(signs, [true, true]);
+    }
+}
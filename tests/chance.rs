@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use rantz_random::chance::*;
+    use rantz_random::Rng;
+
+    const ROLLS: usize = 20000;
+
+    fn frequency(mut roll: impl FnMut() -> bool) -> f64 {
+        (0..ROLLS).filter(|_| roll()).count() as f64 / ROLLS as f64
+    }
+
+    #[test]
+    fn probabilities_match() {
+        let mut rng = Rng::with_seed(1);
+
+        assert!((frequency(|| chance_with(&mut rng, 0.35)) - 0.35).abs() < 0.02);
+        assert!((frequency(|| one_in_with(&mut rng, 20)) - 0.05).abs() < 0.01);
+        assert!((frequency(|| odds_with(&mut rng, 3, 7)) - 0.3).abs() < 0.02);
+        assert!((frequency(|| percent_with(&mut rng, 15)) - 0.15).abs() < 0.02);
+    }
+
+    #[test]
+    fn edge_cases() {
+        for _ in 0..1000 {
+            assert!(!chance(0.0));
+            assert!(!chance(-1.0));
+            assert!(!chance(f64::NAN));
+            assert!(chance(1.0));
+            assert!(chance(2.0));
+            assert!(one_in(1));
+            assert!(!one_in(0));
+            assert!(!odds(0, 5));
+            assert!(odds(5, 0));
+            assert!(!odds(0, 0));
+            assert!(!percent(0));
+            assert!(percent(100));
+            assert!(percent(250));
+        }
+    }
+
+    #[test]
+    fn seeded_rolls_are_reproducible() {
+        let mut a = Rng::with_seed(2);
+        let mut b = Rng::with_seed(2);
+
+        for _ in 0..100 {
+            assert_eq!(chance_with(&mut a, 0.5), chance_with(&mut b, 0.5));
+            assert_eq!(
+                roll_check_with(&mut a, 3, 12),
+                roll_check_with(&mut b, 3, 12)
+            );
+        }
+    }
+
+    #[test]
+    fn checks_are_graded() {
+        let mut rng = Rng::with_seed(3);
+        let outcomes = (0..ROLLS)
+            .map(|_| roll_check_with(&mut rng, 0, 11))
+            .collect::<Vec<_>>();
+        let share = |outcome: CheckOutcome| {
+            outcomes.iter().filter(|o| **o == outcome).count() as f64 / ROLLS as f64
+        };
+
+        assert!((share(CheckOutcome::CriticalFailure) - 0.05).abs() < 0.01);
+        assert!((share(CheckOutcome::CriticalSuccess) - 0.05).abs() < 0.01);
+        // 11 to 19 succeed, 2 to 10 fail
+        assert!((share(CheckOutcome::Success) - 0.45).abs() < 0.02);
+        assert!((share(CheckOutcome::Failure) - 0.45).abs() < 0.02);
+    }
+
+    #[test]
+    fn naturals_override_modifiers() {
+        let mut rng = Rng::with_seed(4);
+        for _ in 0..1000 {
+            assert_ne!(roll_check_with(&mut rng, 100, 10), CheckOutcome::Failure);
+            assert_ne!(roll_check_with(&mut rng, -100, 10), CheckOutcome::Success);
+        }
+
+        assert!(CheckOutcome::Success.is_success());
+        assert!(!CheckOutcome::CriticalFailure.is_success());
+        assert!(CheckOutcome::CriticalSuccess.is_critical());
+        assert!(CheckOutcome::CriticalFailure < CheckOutcome::Failure);
+    }
+}
@@ -0,0 +1,135 @@
+#![cfg(feature = "bevy")]
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use rantz_random::color::*;
+    use rantz_random::{Distribution, RandomWeightedContainer, Rng};
+
+    fn hue_distance(a: f32, b: f32) -> f32 {
+        let difference = (a - b).rem_euclid(360.0);
+        difference.min(360.0 - difference)
+    }
+
+    #[test]
+    fn hsl_components_stay_in_their_ranges() {
+        let range = HslRange::new()
+            .with_hue(200.0, 240.0)
+            .with_saturation(0.5, 0.7)
+            .with_lightness(0.2, 0.3);
+        let mut rng = Rng::with_seed(1);
+
+        for _ in 0..1000 {
+            let color: Hsla = range.sample(&mut rng);
+            assert!((200.0..=240.0).contains(&color.hue));
+            assert!((0.5..=0.7).contains(&color.saturation));
+            assert!((0.2..=0.3).contains(&color.lightness));
+        }
+    }
+
+    #[test]
+    fn hue_ranges_cross_zero() {
+        let range = HsvRange::new().with_hue(330.0, 30.0);
+        let mut rng = Rng::with_seed(2);
+        let mut below = false;
+        let mut above = false;
+
+        for _ in 0..1000 {
+            let color: Hsva = range.sample(&mut rng);
+            assert!((0.0..360.0).contains(&color.hue));
+            assert!(hue_distance(color.hue, 0.0) <= 30.001, "{}", color.hue);
+            below |= color.hue > 180.0;
+            above |= color.hue < 180.0;
+        }
+
+        assert!(below && above);
+    }
+
+    #[test]
+    fn oklch_ranges_convert_to_colors() {
+        let range = OklchRange::new()
+            .with_lightness(0.7, 0.7)
+            .with_chroma(0.0, 0.0);
+        let mut rng = Rng::with_seed(3);
+        let color: Color = range.sample(&mut rng);
+        let lightness = Oklcha::from(color).lightness;
+
+        assert!((lightness - 0.7).abs() < 1e-3);
+    }
+
+    #[test]
+    fn golden_ratio_palettes_spread_hues() {
+        let palette = Palette::new(HueScheme::GoldenRatio)
+            .with_base_hue(10.0)
+            .generate_with(8, &mut Rng::with_seed(4));
+        let hues: Vec<f32> = palette
+            .iter()
+            .map(|color| Oklcha::from(*color).hue)
+            .collect();
+
+        assert_eq!(hues.len(), 8);
+        for (i, a) in hues.iter().enumerate() {
+            for b in &hues[i + 1..] {
+                assert!(hue_distance(*a, *b) > 20.0, "{a} and {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn scheme_hues_follow_the_base() {
+        let mut rng = Rng::with_seed(5);
+        let hues = |scheme, count, rng: &mut Rng| -> Vec<f32> {
+            Palette::new(scheme)
+                .with_base_hue(50.0)
+                .generate_with(count, rng)
+                .iter()
+                .map(|color| Oklcha::from(*color).hue)
+                .collect()
+        };
+
+        let triadic = hues(HueScheme::Triadic, 3, &mut rng);
+        for (hue, expected) in triadic.iter().zip([50.0, 170.0, 290.0]) {
+            assert!(hue_distance(*hue, expected) < 0.5, "{hue}");
+        }
+
+        let complementary = hues(HueScheme::Complementary, 2, &mut rng);
+        assert!(hue_distance(complementary[1], 230.0) < 0.5);
+
+        let analogous = hues(HueScheme::Analogous(20.0), 5, &mut rng);
+        for hue in analogous {
+            assert!(hue_distance(hue, 50.0) <= 20.5, "{hue}");
+        }
+    }
+
+    #[test]
+    fn weighted_palettes_keep_their_weights() {
+        let table = Palette::new(HueScheme::Triadic).generate_weighted(&[6, 3, 1]);
+
+        assert_eq!(table.weights(), &vec![6, 3, 1]);
+        assert!(table.weighted_random().is_some());
+    }
+
+    #[test]
+    fn jitter_stays_close_to_the_base_color() {
+        let base = Color::oklch(0.6, 0.1, 120.0);
+        let jitter = ColorJitter::new(10.0, 0.05, 0.02);
+        let mut rng = Rng::with_seed(6);
+
+        for _ in 0..500 {
+            let color = Oklcha::from(jitter.jitter_with(base, &mut rng));
+            assert!(hue_distance(color.hue, 120.0) <= 10.01);
+            assert!((color.lightness - 0.6).abs() <= 0.0501);
+            assert!((color.chroma - 0.1).abs() <= 0.0201);
+        }
+    }
+
+    #[test]
+    fn seeded_palettes_replay() {
+        let palette = Palette::new(HueScheme::GoldenRatio);
+
+        assert_eq!(
+            palette.generate_with(5, &mut Rng::with_seed(7)),
+            palette.generate_with(5, &mut Rng::with_seed(7))
+        );
+    }
+}
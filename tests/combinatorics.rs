@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use rantz_random::combinatorics::*;
+
+    #[test]
+    fn permutation_contains_every_index_once() {
+        let mut p = permutation(50);
+        p.sort();
+
+        assert_eq!(p, (0..50).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn derangement_moves_every_index() {
+        for n in [0, 2, 3, 10, 100] {
+            let d = derangement(n).unwrap();
+            let mut sorted = d.clone();
+            sorted.sort();
+
+            assert_eq!(sorted, (0..n).collect::<Vec<_>>());
+            assert!(d.iter().enumerate().all(|(i, &p)| i != p));
+        }
+    }
+
+    #[test]
+    fn derangement_of_one_is_none() {
+        assert_eq!(derangement(1), None);
+    }
+
+    #[test]
+    fn derangements_of_three_are_uniform() {
+        // The only derangements of 3 elements are the two rotations
+        let mut first = 0;
+        for _ in 0..2000 {
+            match derangement(3).unwrap().as_slice() {
+                [1, 2, 0] => first += 1,
+                [2, 0, 1] => {}
+                other => panic!("{:?} is not a derangement", other),
+            }
+        }
+
+        assert!((800..1200).contains(&first));
+    }
+
+    #[test]
+    fn combination_is_sorted_and_distinct() {
+        let c = combination(100, 10).unwrap();
+
+        assert_eq!(c.len(), 10);
+        assert!(c.windows(2).all(|w| w[0] < w[1]));
+        assert!(c.iter().all(|&i| i < 100));
+    }
+
+    #[test]
+    fn combination_edge_cases() {
+        assert_eq!(combination(5, 0), Some(vec![]));
+        assert_eq!(combination(5, 5), Some(vec![0, 1, 2, 3, 4]));
+        assert_eq!(combination(5, 6), None);
+    }
+
+    #[test]
+    fn set_partition_into_has_k_non_empty_blocks() {
+        let partition = set_partition_into(20, 4).unwrap();
+        let mut all = partition.iter().flatten().cloned().collect::<Vec<_>>();
+        all.sort();
+
+        assert_eq!(partition.len(), 4);
+        assert!(partition.iter().all(|block| !block.is_empty()));
+        assert_eq!(all, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn set_partition_into_edge_cases() {
+        assert_eq!(set_partition_into(0, 0), Some(vec![]));
+        assert_eq!(set_partition_into(3, 0), None);
+        assert_eq!(set_partition_into(3, 4), None);
+        assert_eq!(
+            set_partition_into(3, 3),
+            Some(vec![vec![0], vec![1], vec![2]])
+        );
+        assert_eq!(set_partition_into(3, 1), Some(vec![vec![0, 1, 2]]));
+    }
+
+    #[test]
+    fn set_partition_into_is_uniform() {
+        // {0,1,2} into 2 blocks: {0}{1,2}, {0,1}{2}, {0,2}{1}
+        let mut counts = [0; 3];
+        for _ in 0..3000 {
+            let partition = set_partition_into(3, 2).unwrap();
+            match partition.iter().find(|b| b.len() == 1).unwrap()[0] {
+                0 => counts[0] += 1,
+                2 => counts[1] += 1,
+                _ => counts[2] += 1,
+            }
+        }
+
+        assert!(counts.iter().all(|c| (850..1150).contains(c)));
+    }
+
+    #[test]
+    fn set_partition_covers_every_index() {
+        let partition = set_partition(30);
+        let mut all = partition.iter().flatten().cloned().collect::<Vec<_>>();
+        all.sort();
+
+        assert_eq!(all, (0..30).collect::<Vec<_>>());
+        assert!(set_partition(0).is_empty());
+    }
+}
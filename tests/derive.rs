@@ -0,0 +1,193 @@
+#![cfg(feature = "derive")]
+
+#[cfg(test)]
+mod tests {
+    use rantz_random::{Random, RandomRange};
+
+    #[derive(Random, Debug, Clone, Copy, PartialEq)]
+    enum Rarity {
+        #[random(weight = 90)]
+        Common,
+        #[random(weight = 10)]
+        Rare,
+        #[random(skip)]
+        #[allow(dead_code)]
+        Unique,
+    }
+
+    #[derive(Random, Debug, PartialEq)]
+    enum Element {
+        Fire,
+        Water,
+        Earth,
+        Air,
+    }
+
+    #[derive(Random, Debug)]
+    struct Loot {
+        rarity: Rarity,
+        element: Element,
+        stack: u8,
+    }
+
+    #[derive(Random, Debug)]
+    struct Pair(bool, Element);
+
+    #[derive(Random, Debug, PartialEq)]
+    struct Marker;
+
+    #[derive(Random, Debug)]
+    #[allow(dead_code)]
+    enum Payload {
+        Empty,
+        Tuple(u8, bool),
+        Named { value: i32 },
+    }
+
+    #[derive(Random, Debug)]
+    struct Wrapper<T> {
+        inner: T,
+    }
+
+    fn spawn_name() -> String {
+        "Goblin".to_string()
+    }
+
+    #[derive(Random, Debug, Clone, Copy, PartialEq)]
+    struct EnemyStats {
+        #[random(range = 10..=20)]
+        hp: u32,
+        #[random(range = 1.0..=2.0)]
+        speed: f32,
+    }
+
+    #[derive(RandomRange, Debug, Clone, Copy, PartialEq)]
+    struct BaseStats {
+        hp: u32,
+        speed: f32,
+    }
+
+    #[derive(Random, Debug)]
+    struct Enemy {
+        stats: EnemyStats,
+        #[random(with = spawn_name)]
+        name: String,
+        #[random(default)]
+        level: u8,
+    }
+
+    #[derive(RandomRange, Debug)]
+    struct Spread(
+        i32,
+        #[random(default)] Option<u8>,
+        #[random(with = spawn_name)] String,
+    );
+
+    #[test]
+    fn skipped_variants_are_never_chosen() {
+        for _ in 0..1000 {
+            assert_ne!(Rarity::random(), Rarity::Unique);
+        }
+    }
+
+    #[test]
+    fn weights_bias_variant_choice() {
+        let rare = (0..10000)
+            .filter(|_| Rarity::random() == Rarity::Rare)
+            .count();
+
+        assert!((800..1200).contains(&rare));
+    }
+
+    #[test]
+    fn unweighted_variants_are_all_chosen() {
+        let mut seen = [false; 4];
+        for _ in 0..1000 {
+            match Element::random() {
+                Element::Fire => seen[0] = true,
+                Element::Water => seen[1] = true,
+                Element::Earth => seen[2] = true,
+                Element::Air => seen[3] = true,
+            }
+        }
+
+        assert!(seen.iter().all(|s| *s));
+    }
+
+    #[test]
+    fn structs_randomize_every_field() {
+        let loot = Loot::random();
+        assert_ne!(loot.rarity, Rarity::Unique);
+        let _ = (loot.element, loot.stack);
+
+        let Pair(flag, element) = Pair::random();
+        let _ = (flag, element);
+
+        assert_eq!(Marker::random(), Marker);
+        assert_ne!(Wrapper::<Rarity>::random().inner, Rarity::Unique);
+    }
+
+    #[test]
+    fn variants_with_fields_are_built() {
+        let mut seen = [false; 3];
+        for _ in 0..1000 {
+            match Payload::random() {
+                Payload::Empty => seen[0] = true,
+                Payload::Tuple(..) => seen[1] = true,
+                Payload::Named { .. } => seen[2] = true,
+            }
+        }
+
+        assert!(seen.iter().all(|s| *s));
+    }
+
+    #[test]
+    fn range_fields_stay_within_bounds() {
+        for _ in 0..1000 {
+            let stats = EnemyStats::random();
+
+            assert!((10..=20).contains(&stats.hp));
+            assert!((1.0..=2.0).contains(&stats.speed));
+        }
+    }
+
+    #[test]
+    fn with_and_default_fields_are_used() {
+        let enemy = Enemy::random();
+
+        assert_eq!(enemy.name, "Goblin");
+        assert_eq!(enemy.level, 0);
+        assert!((10..=20).contains(&enemy.stats.hp));
+    }
+
+    #[test]
+    fn random_range_uses_matching_fields() {
+        let low = BaseStats {
+            hp: 100,
+            speed: 5.0,
+        };
+        let high = BaseStats {
+            hp: 200,
+            speed: 6.0,
+        };
+
+        for _ in 0..1000 {
+            let stats = BaseStats::random_range(low, high);
+
+            assert!((100..=200).contains(&stats.hp));
+            assert!((5.0..=6.0).contains(&stats.speed));
+        }
+    }
+
+    #[test]
+    fn random_range_tuple_structs() {
+        let spread = Spread::random_range(
+            Spread(-5, Some(1), String::new()),
+            Spread(5, Some(2), String::new()),
+        );
+
+        assert!((-5..=5).contains(&spread.0));
+        assert_eq!(spread.1, None);
+        assert_eq!(spread.2, "Goblin");
+    }
+}
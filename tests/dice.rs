@@ -0,0 +1,189 @@
+#[cfg(test)]
+mod tests {
+    use rantz_random::dice::{DiceError, DiceExpr};
+    use rantz_random::Rng;
+
+    fn parse(input: &str) -> DiceExpr {
+        input.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_notation() {
+        assert_eq!(parse("3d6+2").to_string(), "3d6 + 2");
+        assert_eq!(parse("d20").to_string(), "1d20");
+        assert_eq!(parse("d%").to_string(), "1d100");
+        assert_eq!(parse("4D6KH3").to_string(), "4d6kh3");
+        assert_eq!(parse("4d6k3").to_string(), "4d6kh3");
+        assert_eq!(parse("2d20kl1").to_string(), "2d20kl1");
+        assert_eq!(parse("1d6!").to_string(), "1d6!");
+        assert_eq!(
+            parse(" (1d4 + 1) * -2 / 3 ").to_string(),
+            "(1d4 + 1) * -2 / 3"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_notation() {
+        assert_eq!(DiceExpr::parse(""), Err(DiceError::UnexpectedEnd));
+        assert_eq!(DiceExpr::parse("3d6+"), Err(DiceError::UnexpectedEnd));
+        assert_eq!(DiceExpr::parse("(1d6"), Err(DiceError::UnexpectedEnd));
+        assert_eq!(
+            DiceExpr::parse("3d6 x"),
+            Err(DiceError::UnexpectedCharacter {
+                character: 'x',
+                position: 4
+            })
+        );
+        assert_eq!(
+            DiceExpr::parse("0d6"),
+            Err(DiceError::InvalidCount { position: 0 })
+        );
+        assert_eq!(
+            DiceExpr::parse("2d0"),
+            Err(DiceError::InvalidSides { position: 2 })
+        );
+        assert_eq!(
+            DiceExpr::parse("2d6kh3"),
+            Err(DiceError::InvalidKeep { position: 3 })
+        );
+        assert_eq!(
+            DiceExpr::parse("1d1!"),
+            Err(DiceError::InvalidExplode { position: 3 })
+        );
+        assert!(matches!(
+            DiceExpr::parse("99999999999999999999"),
+            Err(DiceError::NumberTooLarge { .. })
+        ));
+    }
+
+    #[test]
+    fn totals_stay_in_bounds() {
+        let expr = parse("3d6+2");
+        let mut rng = Rng::with_seed(1);
+        let mut seen = [false; 21];
+        for _ in 0..5000 {
+            let roll = expr.roll_with(&mut rng);
+            assert!((5..=20).contains(&roll.total));
+            seen[roll.total as usize] = true;
+        }
+
+        assert!(seen[5..=20].iter().all(|seen| *seen));
+    }
+
+    #[test]
+    fn breakdown_matches_total() {
+        let roll = parse("2d8 + 1d4 - 3").roll();
+
+        assert_eq!(roll.groups.len(), 2);
+        assert_eq!(roll.groups[0].notation, "2d8");
+        assert_eq!(roll.groups[0].dice.len(), 2);
+        assert_eq!(roll.groups[1].dice.len(), 1);
+        assert_eq!(roll.total, roll.groups[0].total + roll.groups[1].total - 3);
+    }
+
+    #[test]
+    fn keep_highest_and_lowest() {
+        let mut rng = Rng::with_seed(2);
+        for (notation, highest) in [("4d6kh3", true), ("4d6kl3", false)] {
+            let expr = parse(notation);
+            for _ in 0..200 {
+                let roll = expr.roll_with(&mut rng);
+                let dice = &roll.groups[0].dice;
+                let dropped = dice.iter().filter(|die| !die.kept).collect::<Vec<_>>();
+                assert_eq!(dropped.len(), 1);
+
+                let kept = dice.iter().filter(|die| die.kept).map(|die| die.value);
+                if highest {
+                    assert!(kept.clone().all(|value| value >= dropped[0].value));
+                } else {
+                    assert!(kept.clone().all(|value| value <= dropped[0].value));
+                }
+                assert_eq!(roll.total, kept.sum::<i64>());
+            }
+        }
+    }
+
+    #[test]
+    fn exploding_dice_reroll_the_highest_face() {
+        let expr = parse("1d2!");
+        let mut rng = Rng::with_seed(3);
+        let mut exploded = false;
+        for _ in 0..200 {
+            let roll = expr.roll_with(&mut rng);
+            let die = &roll.groups[0].dice[0];
+            let (last, rest) = die.faces.split_last().unwrap();
+
+            assert_eq!(*last, 1);
+            assert!(rest.iter().all(|face| *face == 2));
+            assert_eq!(
+                die.value,
+                die.faces.iter().map(|face| *face as i64).sum::<i64>()
+            );
+            exploded |= !rest.is_empty();
+        }
+
+        assert!(exploded);
+    }
+
+    #[test]
+    fn arithmetic_precedence_and_division() {
+        assert_eq!(parse("2 + 3 * 4").roll().total, 14);
+        assert_eq!(parse("(2 + 3) * 4").roll().total, 20);
+        assert_eq!(parse("7 / 2").roll().total, 3);
+        assert_eq!(parse("-7 / 2").roll().total, -4);
+        assert_eq!(parse("5 / 0").roll().total, 0);
+        assert_eq!(parse("10 - 2 - 3").roll().total, 5);
+    }
+
+    #[test]
+    fn seeded_rolls_are_reproducible() {
+        use rantz_random::Distribution;
+
+        let expr = parse("4d6kh3 + 1d6!");
+        let mut a = Rng::with_seed(4);
+        let mut b = Rng::with_seed(4);
+
+        for _ in 0..50 {
+            assert_eq!(expr.sample(&mut a), expr.roll_with(&mut b).total);
+        }
+    }
+
+    #[test]
+    fn whitespace_is_allowed_between_any_tokens() {
+        for input in ["3 d6", "3d 6", "3 d 6"] {
+            assert_eq!(parse(input).to_string(), "3d6");
+        }
+        assert_eq!(parse("d 6").to_string(), "1d6");
+        for input in ["4d6 kh 3", "4d6 k h 3", "4d6kh 3", "4 d 6 k 3"] {
+            assert_eq!(parse(input).to_string(), "4d6kh3");
+        }
+        assert_eq!(parse("2d20 kl 1").to_string(), "2d20kl1");
+        assert_eq!(parse("1d6 ! + 2").to_string(), "1d6! + 2");
+    }
+
+    #[test]
+    fn deep_nesting_is_an_error() {
+        let too_deep =
+            |input: &str| matches!(DiceExpr::parse(input), Err(DiceError::TooDeep { .. }));
+
+        assert!(too_deep(&("-".repeat(50_000) + "1")));
+        assert!(too_deep(&"(".repeat(50_000)));
+        assert!(too_deep(
+            &("(".repeat(40) + &"-".repeat(40) + "1" + &")".repeat(40))
+        ));
+
+        assert!(DiceExpr::parse(&("-".repeat(30) + "1d6")).is_ok());
+        assert!(DiceExpr::parse(&("(".repeat(30) + "1d6" + &")".repeat(30))).is_ok());
+    }
+
+    #[test]
+    fn long_flat_chains_are_not_deep() {
+        let sum = parse(&("1+".repeat(100_000) + "1"));
+        assert_eq!(sum.roll().total, 100_001);
+        assert_eq!(sum.probabilities().unwrap().min(), 100_001);
+
+        let mixed = parse(&("2*3+".repeat(50_000) + "1"));
+        assert_eq!(mixed.roll().total, 300_001);
+        assert_eq!(mixed.to_string().len(), "2 * 3 + ".len() * 50_000 + 1);
+    }
+}
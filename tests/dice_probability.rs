@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+    use rantz_random::dice::DiceExpr;
+    use rantz_random::{Distribution, Rng};
+
+    fn odds(input: &str) -> rantz_random::dice::DiceProbabilities {
+        input.parse::<DiceExpr>().unwrap().probabilities().unwrap()
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{} is not within {} of {}",
+            actual,
+            tolerance,
+            expected
+        );
+    }
+
+    #[test]
+    fn single_die_is_uniform() {
+        let d6 = odds("1d6");
+
+        assert_eq!(d6.min(), 1);
+        assert_eq!(d6.max(), 6);
+        for face in 1..=6 {
+            assert_close(d6.probability(face), 1.0 / 6.0, 1e-12);
+        }
+        assert_eq!(d6.probability(0), 0.0);
+        assert_eq!(d6.probability(7), 0.0);
+        assert_close(d6.mean(), 3.5, 1e-12);
+        assert_close(d6.variance(), 35.0 / 12.0, 1e-12);
+    }
+
+    #[test]
+    fn sums_convolve() {
+        let three_d6 = odds("3d6 + 2");
+
+        assert_eq!(three_d6.min(), 5);
+        assert_eq!(three_d6.max(), 20);
+        assert_close(three_d6.probability(12), 27.0 / 216.0, 1e-12);
+        assert_close(three_d6.mean(), 12.5, 1e-12);
+        assert_close(three_d6.variance(), 3.0 * 35.0 / 12.0, 1e-9);
+        assert_close(three_d6.at_least(5), 1.0, 1e-12);
+        assert_close(three_d6.at_least(20), 1.0 / 216.0, 1e-12);
+        assert_close(three_d6.at_most(6), 4.0 / 216.0, 1e-12);
+    }
+
+    #[test]
+    fn keep_highest_matches_enumeration() {
+        let mut counts = [0u32; 19];
+        for a in 1..=6 {
+            for b in 1..=6 {
+                for c in 1..=6 {
+                    for d in 1..=6 {
+                        let mut dice = [a, b, c, d];
+                        dice.sort();
+                        counts[dice[1] + dice[2] + dice[3]] += 1;
+                    }
+                }
+            }
+        }
+
+        let stat = odds("4d6kh3");
+        assert_eq!(stat.min(), 3);
+        assert_eq!(stat.max(), 18);
+        for (total, count) in counts.iter().enumerate().skip(3) {
+            assert_close(
+                stat.probability(total as i64),
+                *count as f64 / 1296.0,
+                1e-12,
+            );
+        }
+        assert_close(stat.mean(), 15869.0 / 1296.0, 1e-9);
+    }
+
+    #[test]
+    fn keep_lowest_of_two_is_disadvantage() {
+        let disadvantage = odds("2d20kl1");
+
+        for face in 1..=20 {
+            let expected = (2.0 * (20 - face) as f64 + 1.0) / 400.0;
+            assert_close(disadvantage.probability(face), expected, 1e-12);
+        }
+    }
+
+    #[test]
+    fn exploding_dice() {
+        let exploding = odds("1d6!");
+
+        assert_eq!(exploding.min(), 1);
+        assert_eq!(exploding.probability(6), 0.0);
+        assert_close(exploding.probability(5), 1.0 / 6.0, 1e-12);
+        assert_close(exploding.probability(7), 1.0 / 36.0, 1e-12);
+        assert_close(exploding.mean(), 4.2, 1e-9);
+        assert_close(exploding.iter().map(|(_, p)| p).sum(), 1.0, 1e-9);
+    }
+
+    #[test]
+    fn arithmetic_between_terms() {
+        let doubled = odds("1d4 * 2");
+        assert_eq!(doubled.min(), 2);
+        assert_eq!(doubled.max(), 8);
+        assert_eq!(doubled.probability(3), 0.0);
+        assert_close(doubled.probability(4), 0.25, 1e-12);
+
+        let halved = odds("1d4 / 2");
+        assert_close(halved.probability(0), 0.25, 1e-12);
+        assert_close(halved.probability(1), 0.5, 1e-12);
+        assert_close(halved.probability(2), 0.25, 1e-12);
+
+        let negated = odds("-1d6 + 10");
+        assert_eq!(negated.min(), 4);
+        assert_eq!(negated.max(), 9);
+    }
+
+    #[test]
+    fn too_many_outcomes_returns_none() {
+        let huge: DiceExpr = "1d4000000000".parse().unwrap();
+
+        assert!(huge.probabilities().is_none());
+    }
+
+    #[test]
+    fn weighted_table_follows_the_odds() {
+        let table = odds("2d6").to_weighted_table();
+        let mut rng = Rng::with_seed(1);
+        let mut counts = [0u32; 13];
+        for _ in 0..36000 {
+            counts[table.sample(&mut rng) as usize] += 1;
+        }
+
+        assert_eq!(counts[0] + counts[1], 0);
+        assert_close(counts[7] as f64 / 36000.0, 6.0 / 36.0, 0.01);
+        assert_close(counts[2] as f64 / 36000.0, 1.0 / 36.0, 0.005);
+    }
+}
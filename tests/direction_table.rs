@@ -0,0 +1,163 @@
+#![cfg(feature = "spatial2d")]
+
+#[cfg(test)]
+mod tests {
+    use rantz_random::{DirectionTable, Rng, WeightedTable};
+    use rantz_spatial2d::prelude::*;
+
+    #[test]
+    fn empty_tables_pick_nothing() {
+        assert_eq!(DirectionTable::<CompassRose>::new().random(), None);
+    }
+
+    #[test]
+    fn uniform_tables_pick_every_point() {
+        let table = DirectionTable::<Compass>::uniform();
+        let mut rng = Rng::with_seed(1);
+        let mut seen = [false; 4];
+
+        for _ in 0..200 {
+            let index = match table.random_with(&mut rng).unwrap() {
+                Compass::N => 0,
+                Compass::E => 1,
+                Compass::S => 2,
+                Compass::W => 3,
+            };
+            seen[index] = true;
+        }
+
+        assert_eq!(seen, [true; 4]);
+    }
+
+    #[test]
+    fn picks_follow_weights() {
+        let table = DirectionTable::<CompassRose>::new()
+            .with_weight(CompassRose::W, 3)
+            .with_weight(CompassRose::N, 1);
+        let mut rng = Rng::with_seed(2);
+
+        let west = (0..4000)
+            .filter(|_| table.random_with(&mut rng) == Some(CompassRose::W))
+            .count();
+
+        assert_eq!(table.weight(CompassRose::S), 0);
+        assert!((2800..3200).contains(&west), "{west}");
+    }
+
+    #[test]
+    fn towards_favours_points_near_the_heading() {
+        let table = DirectionTable::<CompassRose>::towards(Degrees::new(45.0), 2.0);
+
+        assert!(table.weight(CompassRose::NE) > table.weight(CompassRose::N));
+        assert_eq!(table.weight(CompassRose::N), table.weight(CompassRose::E));
+        assert!(table.weight(CompassRose::N) > table.weight(CompassRose::NW));
+        assert!(table.weight(CompassRose::NW) > table.weight(CompassRose::SW));
+        assert_eq!(table.weight(CompassRose::NW), table.weight(CompassRose::SE));
+    }
+
+    #[test]
+    fn towards_takes_radians_and_wraps_around() {
+        let from_radians = DirectionTable::<CompassHalfwinds>::towards(
+            Radians::new(-std::f32::consts::FRAC_PI_2),
+            1.0,
+        );
+        let from_degrees = DirectionTable::<CompassHalfwinds>::towards(Degrees::new(270.0), 1.0);
+
+        assert_eq!(from_radians, from_degrees);
+        assert_eq!(
+            from_degrees.iter().max_by_key(|(_, weight)| *weight),
+            Some((CompassHalfwinds::S, 1000))
+        );
+    }
+
+    #[test]
+    fn zero_concentration_is_uniform() {
+        let table = DirectionTable::<CompassHalfwinds>::towards(Degrees::new(10.0), 0.0);
+
+        assert!(table.iter().all(|(_, weight)| weight == 1000));
+    }
+
+    #[test]
+    fn bias_scales_existing_weights() {
+        let table = DirectionTable::<Compass>::uniform()
+            .with_weight(Compass::N, 100)
+            .with_weight(Compass::S, 100)
+            .biased_towards(Degrees::new(90.0), 1.0);
+
+        assert_eq!(table.weight(Compass::N), 100);
+        assert_eq!(table.weight(Compass::S), 14);
+        assert_eq!(table.weight(Compass::E), 0);
+    }
+
+    #[test]
+    fn converts_to_and_from_weighted_tables() {
+        let weighted: WeightedTable<CompassRose> = [(CompassRose::NE, 5), (CompassRose::SW, 2)]
+            .into_iter()
+            .collect();
+        let table = DirectionTable::from(&weighted);
+
+        assert_eq!(table.weight(CompassRose::NE), 5);
+        assert_eq!(table.weight(CompassRose::SW), 2);
+        assert_eq!(table.total_weight(), 7);
+
+        let back = WeightedTable::from(table);
+        assert_eq!(back.get_weight(&CompassRose::NE), Some(5));
+        assert_eq!(back.get_weight(&CompassRose::E), Some(0));
+    }
+
+    #[test]
+    fn towards_between_points_keeps_the_closest() {
+        let table = DirectionTable::<Compass>::towards(Degrees::new(45.0), 100.0);
+
+        assert_eq!(table.weight(Compass::N), 1000);
+        assert_eq!(table.weight(Compass::E), 1000);
+        assert_eq!(table.weight(Compass::S), 0);
+        assert!(matches!(table.random(), Some(Compass::N | Compass::E)));
+    }
+
+    #[test]
+    fn high_concentrations_keep_the_closest_point() {
+        for concentration in [300.0, 400.0, 600.0, 1e30, f32::INFINITY] {
+            let table = DirectionTable::<CompassRose>::towards(Degrees::new(30.0), concentration);
+
+            assert_eq!(table.weight(CompassRose::NE), 1000, "{concentration}");
+            assert_eq!(table.total_weight(), 1000, "{concentration}");
+            assert_eq!(table.random(), Some(CompassRose::NE));
+
+            let on_point = DirectionTable::<Compass>::towards(Degrees::new(90.0), concentration);
+            assert_eq!(on_point.weight(Compass::N), 1000, "{concentration}");
+        }
+
+        let nan = DirectionTable::<Compass>::towards(Degrees::new(0.0), f32::NAN);
+        assert_eq!(
+            nan,
+            DirectionTable::<Compass>::towards(Degrees::new(0.0), 0.0)
+        );
+    }
+
+    #[test]
+    fn strong_bias_keeps_the_closest_weighted_point() {
+        let table = DirectionTable::<CompassRose>::new()
+            .with_weight(CompassRose::W, 5)
+            .with_weight(CompassRose::SW, 3)
+            .biased_towards(Degrees::new(30.0), 1000.0);
+
+        assert_eq!(table.weight(CompassRose::W), 5);
+        assert_eq!(table.weight(CompassRose::SW), 0);
+        assert_eq!(table.random(), Some(CompassRose::W));
+    }
+
+    #[test]
+    fn huge_weights_do_not_overflow() {
+        let table = DirectionTable::<Compass>::new()
+            .with_weight(Compass::N, u32::MAX)
+            .with_weight(Compass::E, u32::MAX);
+        let mut rng = Rng::with_seed(3);
+
+        assert_eq!(table.total_weight(), 2 * u32::MAX as u64);
+        let east = (0..2000)
+            .filter(|_| table.random_with(&mut rng) == Some(Compass::E))
+            .count();
+        assert!((800..1200).contains(&east), "{east}");
+    }
+}
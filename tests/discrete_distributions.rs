@@ -0,0 +1,169 @@
+#[cfg(test)]
+mod tests {
+    use rantz_random::distributions::*;
+    use rantz_random::Rng;
+
+    const SAMPLES: usize = 20000;
+
+    fn mean_and_variance(mut sample: impl FnMut() -> u64) -> (f64, f64) {
+        let values = (0..SAMPLES).map(|_| sample() as f64).collect::<Vec<_>>();
+        let mean = values.iter().sum::<f64>() / SAMPLES as f64;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / SAMPLES as f64;
+        (mean, variance)
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{} is not within {} of {}",
+            actual,
+            tolerance,
+            expected
+        );
+    }
+
+    #[test]
+    fn invalid_parameters_return_none() {
+        assert!(Bernoulli::new(1.5).is_none());
+        assert!(Binomial::new(10, -0.1).is_none());
+        assert!(Geometric::<u64>::new(0.0).is_none());
+        assert!(Poisson::<u64>::new(0.0).is_none());
+        assert!(Poisson::<u64>::new(f64::INFINITY).is_none());
+        assert!(Hypergeometric::new(10, 11, 5).is_none());
+        assert!(Hypergeometric::new(10, 5, 11).is_none());
+        assert!(Zipf::new(0, 1.0).is_none());
+        assert!(Zipf::new(10, -1.0).is_none());
+        assert!(Binomial::new(-1, 0.5).is_none());
+        assert!(Hypergeometric::new(10, -1, 5).is_none());
+        assert!(Uniform::new(5, 4).is_none());
+    }
+
+    #[test]
+    fn bernoulli_frequency() {
+        let bernoulli = Bernoulli::new(0.3).unwrap();
+        let mut rng = Rng::with_seed(1);
+        let (mean, _) = mean_and_variance(|| bernoulli.sample(&mut rng) as u64);
+
+        assert_close(mean, 0.3, 0.02);
+        assert!(!Bernoulli::new(0.0).unwrap().sample_random());
+        assert!(Bernoulli::new(1.0).unwrap().sample_random());
+    }
+
+    #[test]
+    fn binomial_moments() {
+        let mut rng = Rng::with_seed(2);
+
+        // Small n * p, waiting time path
+        let binomial = Binomial::new(20, 0.25).unwrap();
+        let (mean, variance) = mean_and_variance(|| {
+            let value = binomial.sample(&mut rng);
+            assert!(value <= 20);
+            value
+        });
+        assert_close(mean, 5.0, 0.1);
+        assert_close(variance, 3.75, 0.2);
+
+        // Large n and p > 0.5, beta splitting path
+        let binomial = Binomial::new(1000, 0.7).unwrap();
+        let (mean, variance) = mean_and_variance(|| {
+            let value = binomial.sample(&mut rng);
+            assert!(value <= 1000);
+            value
+        });
+        assert_close(mean, 700.0, 1.0);
+        assert_close(variance, 210.0, 12.0);
+    }
+
+    #[test]
+    fn geometric_moments() {
+        let geometric = Geometric::new(0.25).unwrap();
+        let mut rng = Rng::with_seed(3);
+        let (mean, _) = mean_and_variance(|| geometric.sample(&mut rng));
+
+        assert_close(mean, 3.0, 0.1);
+        assert_eq!(Geometric::<u64>::new(1.0).unwrap().sample_random(), 0);
+    }
+
+    #[test]
+    fn poisson_moments() {
+        let mut rng = Rng::with_seed(4);
+
+        for lambda in [4.5, 250.0] {
+            let poisson = Poisson::new(lambda).unwrap();
+            let (mean, variance) = mean_and_variance(|| poisson.sample(&mut rng));
+
+            assert_close(mean, lambda, lambda.sqrt() * 0.05);
+            assert_close(variance, lambda, lambda * 0.06);
+        }
+    }
+
+    #[test]
+    fn hypergeometric_moments() {
+        let mut rng = Rng::with_seed(5);
+
+        // Small and complemented draw counts
+        for draws in [10, 40] {
+            let hypergeometric = Hypergeometric::new(50, 20, draws).unwrap();
+            let (mean, _) = mean_and_variance(|| {
+                let value = hypergeometric.sample(&mut rng);
+                assert!(value <= 20 && value <= draws);
+                value
+            });
+
+            assert_close(mean, draws as f64 * 20.0 / 50.0, 0.1);
+        }
+        assert_eq!(Hypergeometric::new(50, 20, 50).unwrap().sample_random(), 20);
+    }
+
+    #[test]
+    fn zipf_frequencies() {
+        let zipf = Zipf::new(5, 1.0).unwrap();
+        let mut rng = Rng::with_seed(6);
+        let mut counts = [0usize; 5];
+        for _ in 0..SAMPLES {
+            let rank = zipf.sample(&mut rng);
+            assert!((1..=5).contains(&rank));
+            counts[rank as usize - 1] += 1;
+        }
+
+        let harmonic = (1..=5).map(|k| 1.0 / k as f64).sum::<f64>();
+        for (i, count) in counts.iter().enumerate() {
+            let expected = 1.0 / ((i + 1) as f64 * harmonic);
+            assert_close(*count as f64 / SAMPLES as f64, expected, 0.015);
+        }
+    }
+
+    #[test]
+    fn outputs_any_integer_type() {
+        let mut rng = Rng::with_seed(8);
+
+        let crits: u8 = Binomial::new(200u8, 0.5).unwrap().sample(&mut rng);
+        assert!(crits <= 200);
+        let rank: i32 = Zipf::new(3, 1.0).unwrap().sample(&mut rng);
+        assert!((1..=3).contains(&rank));
+        let drawn = Hypergeometric::new(50u16, 20, 10).unwrap().sample(&mut rng);
+        assert!(drawn <= 10);
+
+        // Unbounded counts saturate rather than wrap
+        let crowd: u8 = Poisson::new(1000.0).unwrap().sample(&mut rng);
+        assert_eq!(crowd, u8::MAX);
+        let wait: u8 = Geometric::new(1e-9).unwrap().sample(&mut rng);
+        assert_eq!(wait, u8::MAX);
+
+        let coin = Bernoulli::new(1.0).unwrap().map(u8::from);
+        assert_eq!(coin.sample(&mut rng), 1);
+    }
+
+    #[test]
+    fn uniform_covers_inclusive_range() {
+        let uniform = Uniform::new(-2i8, 2).unwrap();
+        let mut rng = Rng::with_seed(7);
+        let mut seen = [false; 5];
+        for _ in 0..1000 {
+            seen[(uniform.sample(&mut rng) + 2) as usize] = true;
+        }
+
+        assert!(seen.iter().all(|seen| *seen));
+        assert_eq!(Uniform::new(9u64, 9).unwrap().sample_random(), 9);
+    }
+}
@@ -0,0 +1,171 @@
+#[cfg(test)]
+mod tests {
+    use rantz_random::distributions::*;
+    use rantz_random::{Rng, WeightedTable};
+
+    #[test]
+    fn weighted_table_is_a_distribution() {
+        let table = WeightedTable::from_vec(vec![("common", 9), ("rare", 1), ("never", 0)]);
+        let mut rng = Rng::with_seed(1);
+        let rare = table
+            .sample_iter(&mut rng)
+            .take(10000)
+            .inspect(|value| assert_ne!(*value, "never"))
+            .filter(|value| *value == "rare")
+            .count();
+
+        assert!((800..1200).contains(&rare));
+    }
+
+    #[test]
+    fn glob_import_keeps_container_random_and_iterator_map() {
+        use rantz_random::*;
+
+        let table = WeightedTable::from_vec(vec![("only", 1)]);
+
+        assert_eq!(table.random(), Some("only"));
+        assert_eq!(table.sample_random(), "only");
+        // Integer ranges stay plain iterators
+        let doubled: Vec<i32> = (0..3).map(|value| value * 2).collect();
+        assert_eq!(doubled, vec![0, 2, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_weighted_table_panics() {
+        WeightedTable::<u8>::new().sample_random();
+    }
+
+    #[test]
+    fn uniform_covers_float_range() {
+        let uniform = Uniform::new(-1.0f32, 1.0).unwrap();
+        let mut rng = Rng::with_seed(2);
+        let values = uniform.sample_iter(&mut rng).take(5000).collect::<Vec<_>>();
+
+        assert!(values.iter().all(|value| (-1.0..=1.0).contains(value)));
+        assert!(values.iter().any(|value| *value < -0.99));
+        assert!(values.iter().any(|value| *value > 0.99));
+        assert!(Uniform::new(0.0, f64::INFINITY).is_none());
+        assert!(Uniform::new(f64::NAN, 1.0).is_none());
+        assert!(Uniform::new(f64::MIN, f64::MAX)
+            .unwrap()
+            .sample_random()
+            .is_finite());
+    }
+
+    #[test]
+    fn sample_iter_is_reproducible() {
+        let poisson = Poisson::<u64>::new(3.0).unwrap();
+        let mut a = Rng::with_seed(3);
+        let mut b = Rng::with_seed(3);
+
+        let first = poisson.sample_iter(&mut a).take(50).collect::<Vec<_>>();
+        let second = poisson.sample_iter(&mut b).take(50).collect::<Vec<_>>();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn map_and_clamp_compose() {
+        let strength = Normal::new(10.0f32, 5.0)
+            .unwrap()
+            .clamp(3.0, 18.0)
+            .map(|value| value.round() as u8);
+        let mut rng = Rng::with_seed(4);
+        let values = strength
+            .sample_iter(&mut rng)
+            .take(5000)
+            .collect::<Vec<_>>();
+
+        assert!(values.iter().all(|value| (3..=18).contains(value)));
+        assert!(values.contains(&3));
+        assert!(values.contains(&18));
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_with_inverted_bounds_panics() {
+        let _ = Uniform::new(0, 10).unwrap().clamp(5, 4);
+    }
+
+    #[test]
+    fn truncated_rejects_outside_values() {
+        let normal = Normal::new(0.0, 1.0).unwrap().truncated(0.0..);
+        let mut rng = Rng::with_seed(5);
+        let values = normal.sample_iter(&mut rng).take(5000).collect::<Vec<_>>();
+
+        assert!(values.iter().all(|value| *value >= 0.0));
+        // Half normal mean is sqrt(2 / π)
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        assert!((mean - 0.798).abs() < 0.04);
+    }
+
+    #[test]
+    fn mixture_respects_weights() {
+        let damage = mixture([
+            (
+                Box::new(Uniform::new(1, 10).unwrap()) as Box<dyn Distribution<i32>>,
+                3,
+            ),
+            (Box::new(Uniform::new(100, 110).unwrap()), 1),
+            (Box::new(Uniform::new(1000, 2000).unwrap()), 0),
+        ])
+        .unwrap();
+        let mut rng = Rng::with_seed(6);
+        let values = damage.sample_iter(&mut rng).take(8000).collect::<Vec<_>>();
+        let crits = values.iter().filter(|value| **value >= 100).count();
+
+        assert!(values.iter().all(|value| *value < 1000));
+        assert!((1800..2200).contains(&crits));
+    }
+
+    #[test]
+    fn mixture_without_weight_is_none() {
+        let uniform = Uniform::new(0u8, 1).unwrap();
+
+        assert!(Mixture::new([(uniform, 0), (uniform, 0)]).is_none());
+        assert!(Mixture::new(Vec::<(Uniform<u8>, u32)>::new()).is_none());
+        assert!(Mixture::new([(uniform, u32::MAX), (uniform, 1)]).is_none());
+    }
+
+    #[test]
+    fn mixture_method_keeps_its_own_weight() {
+        let normal = Uniform::new(1, 10).unwrap();
+        let critical = Uniform::new(100, 110).unwrap();
+        let damage = normal.mixture(3, [(critical, 1)]).unwrap();
+        let mut rng = Rng::with_seed(7);
+        let crits = damage
+            .sample_iter(&mut rng)
+            .take(8000)
+            .filter(|value| *value >= 100)
+            .count();
+
+        assert!((1800..2200).contains(&crits));
+        assert!(normal.mixture(0, [(critical, 0)]).is_none());
+    }
+
+    #[test]
+    fn float_ranges_are_distributions() {
+        let mut rng = Rng::with_seed(8);
+        let half_open = (-2.0f32..3.0)
+            .map(|value| value * 2.0)
+            .sample_iter(&mut rng)
+            .take(2000)
+            .collect::<Vec<_>>();
+        let closed = (0.5f64..=0.75).sample_iter(&mut rng).take(2000);
+
+        assert!(half_open.iter().all(|value| (-4.0..6.0).contains(value)));
+        assert!(half_open.iter().any(|value| *value < -3.9));
+        assert!(closed
+            .into_iter()
+            .all(|value| (0.5..=0.75).contains(&value)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_float_range_panics() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = 1.0f32..1.0;
+        empty.sample_random();
+    }
+}
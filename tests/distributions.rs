@@ -0,0 +1,205 @@
+#[cfg(test)]
+mod tests {
+    use rantz_random::distributions::*;
+    use rantz_random::Rng;
+
+    const SAMPLES: usize = 20000;
+
+    fn mean_and_variance(mut sample: impl FnMut() -> f64) -> (f64, f64) {
+        let values = (0..SAMPLES).map(|_| sample()).collect::<Vec<_>>();
+        let mean = values.iter().sum::<f64>() / SAMPLES as f64;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / SAMPLES as f64;
+        (mean, variance)
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{} is not within {} of {}",
+            actual,
+            tolerance,
+            expected
+        );
+    }
+
+    #[test]
+    fn invalid_parameters_return_none() {
+        assert!(Normal::new(0.0, -1.0).is_none());
+        assert!(Normal::new(f64::NAN, 1.0).is_none());
+        assert!(LogNormal::new(0.0, -1.0).is_none());
+        assert!(Exponential::new(0.0).is_none());
+        assert!(Gamma::new(0.0, 1.0).is_none());
+        assert!(Beta::new(1.0, 0.0).is_none());
+        assert!(Triangular::new(1.0, 0.0, 0.5).is_none());
+        assert!(Triangular::new(0.0, 1.0, 2.0).is_none());
+        assert!(Cauchy::new(0.0, 0.0).is_none());
+        assert!(Weibull::new(1.0, -1.0).is_none());
+        assert!(Pareto::new(f64::INFINITY, 1.0).is_none());
+        assert!(VonMises::new(0.0, -1.0).is_none());
+        assert!(WrappedNormal::new(f64::NAN, 1.0).is_none());
+    }
+
+    #[test]
+    fn explicit_rngs_are_reproducible() {
+        let normal = Normal::new(10.0f32, 2.0).unwrap();
+        let mut a = Rng::with_seed(1);
+        let mut b = Rng::with_seed(1);
+
+        for _ in 0..100 {
+            assert_eq!(normal.sample(&mut a), normal.sample(&mut b));
+        }
+    }
+
+    #[test]
+    fn global_generator_follows_seed() {
+        let gamma = Gamma::new(2.0, 3.0).unwrap();
+
+        rantz_random::seed(5);
+        let first = gamma.sample_random();
+        rantz_random::seed(5);
+        let second = gamma.sample_random();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn normal_moments() {
+        let normal = Normal::new(50.0, 5.0).unwrap();
+        let mut rng = Rng::with_seed(2);
+        let (mean, variance) = mean_and_variance(|| normal.sample(&mut rng));
+
+        assert_close(mean, 50.0, 0.2);
+        assert_close(variance, 25.0, 1.0);
+    }
+
+    #[test]
+    fn log_normal_moments() {
+        let log_normal = LogNormal::new(0.0, 0.5).unwrap();
+        let mut rng = Rng::with_seed(3);
+        let (mean, _) = mean_and_variance(|| log_normal.sample(&mut rng));
+
+        assert_close(mean, (0.125f64).exp(), 0.02);
+    }
+
+    #[test]
+    fn exponential_moments() {
+        let exponential = Exponential::new(0.5).unwrap();
+        let mut rng = Rng::with_seed(4);
+        let (mean, variance) = mean_and_variance(|| exponential.sample(&mut rng));
+
+        assert_close(mean, 2.0, 0.06);
+        assert_close(variance, 4.0, 0.3);
+    }
+
+    #[test]
+    fn gamma_moments() {
+        for (shape, scale) in [(0.5, 2.0), (3.0, 1.5)] {
+            let gamma = Gamma::new(shape, scale).unwrap();
+            let mut rng = Rng::with_seed(5);
+            let (mean, variance) = mean_and_variance(|| gamma.sample(&mut rng));
+
+            assert_close(mean, shape * scale, 0.05 * shape * scale);
+            assert_close(variance, shape * scale * scale, 0.1 * shape * scale * scale);
+        }
+    }
+
+    #[test]
+    fn beta_moments() {
+        let beta = Beta::new(2.0, 5.0).unwrap();
+        let mut rng = Rng::with_seed(6);
+        let (mean, variance) = mean_and_variance(|| beta.sample(&mut rng));
+
+        assert_close(mean, 2.0 / 7.0, 0.01);
+        assert_close(variance, 10.0 / (49.0 * 8.0), 0.003);
+    }
+
+    #[test]
+    fn triangular_moments_and_bounds() {
+        let triangular = Triangular::new(0.0, 10.0, 7.0).unwrap();
+        let mut rng = Rng::with_seed(7);
+        let (mean, _) = mean_and_variance(|| {
+            let value = triangular.sample(&mut rng);
+            assert!((0.0..=10.0).contains(&value));
+            value
+        });
+
+        assert_close(mean, 17.0 / 3.0, 0.06);
+    }
+
+    #[test]
+    fn cauchy_median() {
+        let cauchy = Cauchy::new(3.0, 1.0).unwrap();
+        let mut rng = Rng::with_seed(8);
+        let mut values = (0..SAMPLES)
+            .map(|_| cauchy.sample(&mut rng))
+            .collect::<Vec<f64>>();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert!(values.iter().all(|v| v.is_finite()));
+        assert_close(values[SAMPLES / 2], 3.0, 0.05);
+    }
+
+    #[test]
+    fn weibull_moments() {
+        // Shape 1 is an exponential with mean `scale`
+        let weibull = Weibull::new(2.0, 1.0).unwrap();
+        let mut rng = Rng::with_seed(9);
+        let (mean, _) = mean_and_variance(|| weibull.sample(&mut rng));
+
+        assert_close(mean, 2.0, 0.06);
+    }
+
+    #[test]
+    fn pareto_moments_and_bounds() {
+        let pareto = Pareto::new(1.0f32, 3.0).unwrap();
+        let mut rng = Rng::with_seed(10);
+        let (mean, _) = mean_and_variance(|| {
+            let value = pareto.sample(&mut rng);
+            assert!(value >= 1.0);
+            value as f64
+        });
+
+        assert_close(mean, 1.5, 0.05);
+    }
+
+    #[test]
+    fn von_mises_concentrates_around_the_mean() {
+        // The mean of cos(θ - μ) is I₁(κ) / I₀(κ), about 0.6978 for κ = 2
+        let von_mises = VonMises::new(3.0f64, 2.0).unwrap();
+        let mut rng = Rng::with_seed(11);
+        let (mean, _) = mean_and_variance(|| {
+            let value = von_mises.sample(&mut rng);
+            assert!((value - 3.0).abs() <= std::f64::consts::PI);
+            (value - 3.0).cos()
+        });
+
+        assert_close(mean, 0.6978, 0.01);
+    }
+
+    #[test]
+    fn von_mises_extremes() {
+        let uniform = VonMises::new(0.0f32, 0.0).unwrap();
+        let sharp = VonMises::new(1.0f32, 1e6).unwrap();
+        let mut rng = Rng::with_seed(12);
+        let (mean, _) = mean_and_variance(|| uniform.sample(&mut rng).cos() as f64);
+
+        assert_close(mean, 0.0, 0.02);
+        for _ in 0..100 {
+            assert!((sharp.sample(&mut rng) - 1.0).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn wrapped_normal_folds_onto_the_circle() {
+        // The mean of cos(θ - μ) is exp(-σ² / 2)
+        let wrapped = WrappedNormal::new(-1.0f64, 1.0).unwrap();
+        let mut rng = Rng::with_seed(13);
+        let (mean, _) = mean_and_variance(|| {
+            let value = wrapped.sample(&mut rng);
+            assert!((value + 1.0).abs() <= std::f64::consts::PI);
+            (value + 1.0).cos()
+        });
+
+        assert_close(mean, (-0.5f64).exp(), 0.01);
+    }
+}
@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod tests {
+    use rantz_random::hash_random::*;
+    use rantz_random::WeightedTable;
+
+    #[test]
+    fn hashes_depend_only_on_their_inputs() {
+        let forward = (-50..50).map(|x| hash_2d(7, x, 3)).collect::<Vec<_>>();
+        let mut backward = (-50..50)
+            .rev()
+            .map(|x| hash_2d(7, x, 3))
+            .collect::<Vec<_>>();
+        backward.reverse();
+
+        assert_eq!(forward, backward);
+        assert_eq!(hash_u32(1, 42), hash_u32(1, 42));
+        assert_eq!(hash_4d(2, 1, 2, 3, 4), hash_4d(2, 1, 2, 3, 4));
+    }
+
+    #[test]
+    fn neighbours_and_seeds_differ() {
+        let base = hash_3d(9, 10, 20, 30);
+        assert_ne!(base, hash_3d(9, 11, 20, 30));
+        assert_ne!(base, hash_3d(9, 10, 21, 30));
+        assert_ne!(base, hash_3d(9, 10, 20, 31));
+        assert_ne!(base, hash_3d(10, 10, 20, 30));
+        assert_ne!(hash_2d(0, 1, 0), hash_2d(0, 0, 1));
+        assert_ne!(hash_u32(0, 0), hash_u32(1, 0));
+    }
+
+    #[test]
+    fn grid_hashes_are_uniform() {
+        let mut buckets = [0; 10];
+        for y in 0..200 {
+            for x in 0..200 {
+                let value = hash_f32(hash_2d(3, x, y));
+                assert!((0.0..1.0).contains(&value));
+                buckets[(value * 10.0) as usize] += 1;
+            }
+        }
+        for count in buckets {
+            assert!((3600..4400).contains(&count), "{:?}", buckets);
+        }
+    }
+
+    #[test]
+    fn unit_conversions_stay_below_one() {
+        assert_eq!(hash_f32(0), 0.0);
+        assert!(hash_f32(u32::MAX) < 1.0);
+        assert!(hash_f64(u32::MAX) < 1.0);
+    }
+
+    #[test]
+    fn integer_ranges_include_both_ends() {
+        assert_eq!(hash_range(0, 1, 6), 1);
+        assert_eq!(hash_range(u32::MAX, 1, 6), 6);
+        assert_eq!(
+            hash_range(u32::MAX, i64::MIN, i64::MAX),
+            i64::MAX - (1 << 32) + 1
+        );
+
+        let mut seen = [false; 6];
+        for x in 0..1000 {
+            let roll: u8 = hash_range(hash_u32(4, x), 1, 6);
+            seen[roll as usize - 1] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn float_ranges_exclude_high() {
+        for x in 0..1000 {
+            let value = hash_range(hash_u32(5, x), -2.0f64, 3.0);
+            assert!((-2.0..3.0).contains(&value));
+        }
+        assert_eq!(hash_range(0, 1.5f32, 2.5), 1.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_integer_range_panics() {
+        hash_range(0, 5, 4);
+    }
+
+    #[test]
+    fn chance_matches_probability() {
+        let hits = (0..10_000)
+            .filter(|&x| hash_chance(hash_u32(6, x), 0.25))
+            .count();
+        assert!((2300..2700).contains(&hits));
+        assert!(!hash_chance(0, 0.0));
+        assert!(hash_chance(u32::MAX, 1.0));
+    }
+
+    #[test]
+    fn choose_covers_every_item() {
+        let items = ['a', 'b', 'c'];
+        let mut counts = [0; 3];
+        for x in 0..3000 {
+            let item = hash_choose(hash_u32(7, x), &items).unwrap();
+            counts[(*item as u8 - b'a') as usize] += 1;
+        }
+        assert!(counts.iter().all(|&c| c > 900));
+        assert_eq!(hash_choose::<char>(0, &[]), None);
+    }
+
+    #[test]
+    fn weighted_picks_follow_weights() {
+        let table: WeightedTable<&str> = [("common", 9), ("never", 0), ("rare", 1)]
+            .into_iter()
+            .collect();
+
+        let mut rare = 0;
+        for x in 0..10_000 {
+            match hash_weighted(hash_u32(8, x), &table) {
+                Some("rare") => rare += 1,
+                Some("common") => {}
+                other => panic!("unexpected pick {:?}", other),
+            }
+        }
+        assert!((850..1150).contains(&rare));
+
+        assert_eq!(hash_weighted(u32::MAX, &table), Some("rare"));
+        assert_eq!(hash_weighted(0, &WeightedTable::<u8>::new()), None);
+    }
+}
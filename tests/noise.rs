@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod tests {
+    use rantz_random::noise::*;
+    use rantz_random::Rng;
+
+    fn samples(noise: &impl Noise, seed: u64) -> Vec<f32> {
+        let mut rng = Rng::with_seed(seed);
+        let mut values = Vec::new();
+        for _ in 0..2000 {
+            let p = [0; 4].map(|_| rng.f32() * 64.0 - 32.0);
+            values.push(noise.noise_1d(p[0]));
+            values.push(noise.noise_2d(p[0], p[1]));
+            values.push(noise.noise_3d(p[0], p[1], p[2]));
+            values.push(noise.noise_4d(p[0], p[1], p[2], p[3]));
+        }
+        values
+    }
+
+    fn assert_fills_range(noise: &impl Noise) {
+        let values = samples(noise, 1);
+        assert!(values.iter().all(|v| (-1.0..=1.0).contains(v)));
+        assert!(values.iter().any(|&v| v > 0.4));
+        assert!(values.iter().any(|&v| v < -0.4));
+    }
+
+    fn assert_continuous(noise: &impl Noise) {
+        let mut rng = Rng::with_seed(2);
+        for _ in 0..2000 {
+            let p = [0; 4].map(|_| rng.f32() * 64.0 - 32.0);
+            let q = p.map(|x| x + 0.001);
+            assert!((noise.noise_1d(p[0]) - noise.noise_1d(q[0])).abs() < 0.02);
+            assert!((noise.noise_2d(p[0], p[1]) - noise.noise_2d(q[0], q[1])).abs() < 0.02);
+            assert!(
+                (noise.noise_3d(p[0], p[1], p[2]) - noise.noise_3d(q[0], q[1], q[2])).abs() < 0.02
+            );
+            assert!(
+                (noise.noise_4d(p[0], p[1], p[2], p[3]) - noise.noise_4d(q[0], q[1], q[2], q[3]))
+                    .abs()
+                    < 0.02
+            );
+        }
+    }
+
+    #[test]
+    fn sources_stay_in_range() {
+        assert_fills_range(&ValueNoise::with_seed(3));
+        assert_fills_range(&Perlin::with_seed(3));
+        assert_fills_range(&OpenSimplex2::with_seed(3));
+    }
+
+    #[test]
+    fn simplex_stays_in_range_for_every_seed() {
+        for seed in 0..50 {
+            let values = samples(&OpenSimplex2::with_seed(seed), seed);
+            assert!(values.iter().all(|v| (-1.0..=1.0).contains(v)), "{seed}");
+        }
+    }
+
+    #[test]
+    fn sources_are_continuous() {
+        assert_continuous(&ValueNoise::with_seed(4));
+        assert_continuous(&Perlin::with_seed(4));
+        assert_continuous(&OpenSimplex2::with_seed(4));
+        assert_continuous(&Fbm::new(OpenSimplex2::with_seed(4)).with_octaves(3));
+    }
+
+    #[test]
+    fn global_seed_reproduces_sources() {
+        rantz_random::seed(11);
+        let first = (Perlin::new(), OpenSimplex2::new(), Worley::new());
+        rantz_random::seed(11);
+        let second = (Perlin::new(), OpenSimplex2::new(), Worley::new());
+
+        assert_eq!(first, second);
+        assert_eq!(samples(&first.1, 5), samples(&second.1, 5));
+    }
+
+    #[test]
+    fn seeds_change_the_noise() {
+        assert_eq!(Perlin::with_seed(6).seed(), 6);
+        assert_eq!(
+            ValueNoise::new_with(&mut Rng::with_seed(8)),
+            ValueNoise::new_with(&mut Rng::with_seed(8))
+        );
+        assert_ne!(
+            samples(&OpenSimplex2::with_seed(6), 7),
+            samples(&OpenSimplex2::with_seed(9), 7)
+        );
+    }
+
+    #[test]
+    fn perlin_is_zero_on_the_lattice() {
+        let perlin = Perlin::with_seed(12);
+        for i in -5..5 {
+            let x = i as f32;
+            assert_eq!(perlin.noise_1d(x), 0.0);
+            assert_eq!(perlin.noise_2d(x, 3.0), 0.0);
+            assert_eq!(perlin.noise_3d(x, -2.0, 7.0), 0.0);
+            assert_eq!(perlin.noise_4d(x, 1.0, 0.0, -9.0), 0.0);
+        }
+    }
+
+    #[test]
+    fn worley_distances_are_ordered() {
+        let f1 = Worley::with_seed(13);
+        let f2 = f1.with_output(WorleyOutput::F2);
+        let edges = f1.with_output(WorleyOutput::F2MinusF1);
+
+        let mut rng = Rng::with_seed(14);
+        for _ in 0..1000 {
+            let (x, y, z) = (rng.f32() * 20.0, rng.f32() * 20.0, rng.f32() * 20.0);
+            let (near, far) = (f1.noise_3d(x, y, z), f2.noise_3d(x, y, z));
+            assert!(near >= 0.0 && near <= far);
+            assert!((edges.noise_3d(x, y, z) - (far - near)).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn worley_without_jitter_is_a_grid() {
+        let grid = Worley::with_seed(15).with_jitter(0.0);
+        assert_eq!(grid.noise_2d(3.5, -1.5), 0.0);
+        assert!((grid.noise_2d(3.0, -1.5) - 0.5).abs() < 1e-6);
+
+        let chebyshev = grid.with_distance(WorleyDistance::Chebyshev);
+        assert!((chebyshev.noise_3d(0.75, 0.6, 0.5) - 0.25).abs() < 1e-6);
+        let manhattan = grid.with_distance(WorleyDistance::Manhattan);
+        assert!((manhattan.noise_3d(0.75, 0.6, 0.5) - 0.35).abs() < 1e-6);
+    }
+
+    #[test]
+    fn worley_cell_values_are_flat_within_a_cell() {
+        let cells = Worley::with_seed(16)
+            .with_jitter(0.0)
+            .with_output(WorleyOutput::CellValue);
+        assert_eq!(cells.noise_2d(4.1, 2.1), cells.noise_2d(4.9, 2.9));
+        assert!(samples(&cells, 17).iter().all(|v| (-1.0..=1.0).contains(v)));
+    }
+
+    #[test]
+    fn single_octave_fbm_is_the_source() {
+        let source = Perlin::with_seed(18);
+        let fbm = Fbm::new(source).with_octaves(1);
+        assert_eq!(samples(&fbm, 19), samples(&source, 19));
+
+        let layered = Fbm::new(source).with_octaves(6).with_gain(0.6);
+        assert!(samples(&layered, 19)
+            .iter()
+            .all(|v| (-1.0..=1.0).contains(v)));
+    }
+
+    #[test]
+    fn ridged_stays_in_range() {
+        let ridged = Ridged::new(OpenSimplex2::with_seed(20)).with_lacunarity(2.5);
+        let values = samples(&ridged, 21);
+        assert!(values.iter().all(|v| (-1.0..=1.0).contains(v)));
+        assert!(values.iter().any(|&v| v > 0.0));
+    }
+
+    #[test]
+    fn domain_warp_moves_the_source() {
+        let source = OpenSimplex2::with_seed(22);
+        let warp = ValueNoise::with_seed(23);
+
+        let still = DomainWarp::new(source, warp, 0.0);
+        assert_eq!(samples(&still, 24), samples(&source, 24));
+
+        let warped = DomainWarp::new(source, warp, 2.0);
+        assert_ne!(samples(&warped, 24), samples(&source, 24));
+    }
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "bevy")]
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use rantz_random::scatter::{PoissonDisk2d, PoissonDisk3d};
+    use rantz_random::Rng;
+
+    fn closest_pair<P: Copy>(points: &[P], distance: impl Fn(P, P) -> f32) -> f32 {
+        let mut closest = f32::INFINITY;
+        for (i, a) in points.iter().enumerate() {
+            for b in &points[i + 1..] {
+                closest = closest.min(distance(*a, *b));
+            }
+        }
+        closest
+    }
+
+    #[test]
+    fn points_keep_their_distance_and_fill_the_area() {
+        let bounds = Rect::new(0.0, 0.0, 50.0, 50.0);
+        let points = PoissonDisk2d::new(bounds, 2.0).generate_with(&mut Rng::with_seed(1));
+
+        assert!(points.iter().all(|point| bounds.contains(*point)));
+        assert!(closest_pair(&points, Vec2::distance) >= 2.0);
+        // Maximal packings at this spacing hold roughly 0.7 / r² points per unit area
+        assert!(points.len() > 350, "only {} points", points.len());
+
+        // No spot is left that could fit another point
+        let mut rng = Rng::with_seed(2);
+        for _ in 0..1000 {
+            let probe = Vec2::new(rng.f32(), rng.f32()) * 50.0;
+            assert!(points.iter().any(|point| point.distance(probe) < 4.0));
+        }
+    }
+
+    #[test]
+    fn polygons_and_accept_functions_limit_the_domain() {
+        let triangle = [Vec2::ZERO, Vec2::new(40.0, 0.0), Vec2::new(0.0, 40.0)];
+        let points = PoissonDisk2d::new(Rect::new(-100.0, -100.0, 100.0, 100.0), 1.5)
+            .within_polygon(&triangle)
+            .with_accept(|point| point.x > 5.0)
+            .generate_with(&mut Rng::with_seed(3));
+
+        assert!(!points.is_empty());
+        for point in &points {
+            assert!(point.x > 5.0 && point.y >= 0.0 && point.x + point.y <= 40.0);
+        }
+    }
+
+    #[test]
+    fn separate_regions_are_all_filled() {
+        let points = PoissonDisk2d::new(Rect::new(0.0, 0.0, 100.0, 10.0), 1.0)
+            .with_accept(|point| point.x < 10.0 || point.x > 90.0)
+            .generate_with(&mut Rng::with_seed(4));
+
+        assert!(points.iter().any(|point| point.x < 10.0));
+        assert!(points.iter().any(|point| point.x > 90.0));
+    }
+
+    #[test]
+    fn variable_radius_spreads_points_out() {
+        let bounds = Rect::new(0.0, 0.0, 40.0, 40.0);
+        let points = PoissonDisk2d::new(bounds, 1.0)
+            .with_radius(4.0, |point| if point.x < 20.0 { 1.0 } else { 4.0 })
+            .generate_with(&mut Rng::with_seed(5));
+
+        let dense = points.iter().filter(|point| point.x < 18.0).count();
+        let sparse = points.iter().filter(|point| point.x > 22.0).count();
+        assert!(dense > sparse * 8, "{} dense vs {} sparse", dense, sparse);
+
+        let sparse = points
+            .iter()
+            .filter(|p| p.x > 22.0)
+            .copied()
+            .collect::<Vec<_>>();
+        assert!(closest_pair(&sparse, Vec2::distance) >= 4.0);
+    }
+
+    #[test]
+    fn three_dimensions() {
+        let points = PoissonDisk3d::new(Vec3::ZERO, Vec3::splat(10.0), 1.0)
+            .with_accept(|point| point.distance(Vec3::splat(5.0)) <= 5.0)
+            .generate_with(&mut Rng::with_seed(6));
+
+        assert!(points.len() > 100, "only {} points", points.len());
+        assert!(closest_pair(&points, Vec3::distance) >= 1.0);
+        assert!(points
+            .iter()
+            .all(|point| point.distance(Vec3::splat(5.0)) <= 5.0));
+    }
+
+    #[test]
+    fn seeded_generation_is_reproducible() {
+        let sampler = PoissonDisk2d::new(Rect::new(0.0, 0.0, 10.0, 10.0), 1.0);
+
+        assert_eq!(
+            sampler.generate_with(&mut Rng::with_seed(7)),
+            sampler.generate_with(&mut Rng::with_seed(7))
+        );
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use rantz_random::{RandomMap, RandomRef, Shuffle};
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+    #[test]
+    fn empty_collections_return_none() {
+        assert_eq!(Vec::<u32>::new().random_ref(), None);
+        assert_eq!(VecDeque::<u32>::new().random_ref(), None);
+        assert_eq!(BTreeSet::<u32>::new().random_ref(), None);
+        assert_eq!(HashSet::<u32>::new().random_ref(), None);
+        assert_eq!(BTreeMap::<u32, u32>::new().random_entry(), None);
+        assert_eq!(HashMap::<u32, u32>::new().random_key(), None);
+        assert_eq!(HashMap::<u32, u32>::new().random_value(), None);
+    }
+
+    #[test]
+    fn slices_and_deques_return_contained_elements() {
+        let slice = [1, 2, 3];
+        let deque = VecDeque::from([4, 5, 6]);
+
+        assert!(slice.contains(slice.random_ref().unwrap()));
+        assert!(deque.contains(deque.random_ref().unwrap()));
+    }
+
+    #[test]
+    fn sets_return_contained_elements() {
+        let btree = BTreeSet::from([1, 2, 3]);
+        let hash = HashSet::from([4, 5, 6]);
+
+        assert!(btree.contains(btree.random_ref().unwrap()));
+        assert!(hash.contains(hash.random_ref().unwrap()));
+    }
+
+    #[test]
+    fn maps_return_matching_entries() {
+        let btree = BTreeMap::from([(1, "one"), (2, "two"), (3, "three")]);
+        let hash = HashMap::from([(1, "one"), (2, "two"), (3, "three")]);
+
+        let (key, value) = btree.random_entry().unwrap();
+        assert_eq!(btree.get(key), Some(value));
+
+        let (key, value) = hash.random_entry().unwrap();
+        assert_eq!(hash.get(key), Some(value));
+
+        assert!(btree.contains_key(btree.random_key().unwrap()));
+        let value = hash.random_value().unwrap();
+        assert!(hash.values().any(|v| v == value));
+    }
+
+    #[test]
+    fn every_element_can_be_selected() {
+        let map = BTreeMap::from([(1, ()), (2, ()), (3, ())]);
+        let mut seen = BTreeSet::new();
+        for _ in 0..1000 {
+            seen.insert(*map.random_key().unwrap());
+        }
+
+        assert_eq!(seen.len(), 3);
+    }
+
+    #[test]
+    fn shuffling_a_deque_keeps_its_elements() {
+        let mut deque = VecDeque::new();
+        deque.extend(0..10);
+        deque.push_front(-1); // Force a non-contiguous layout
+        deque.shuffle();
+
+        let mut sorted = deque.into_iter().collect::<Vec<_>>();
+        sorted.sort();
+        assert_eq!(sorted, (-1..10).collect::<Vec<_>>());
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn indexmap_returns_matching_entries_and_shuffles() {
+        use indexmap::{IndexMap, IndexSet};
+
+        let mut map = (0..10).map(|i| (i, i * 2)).collect::<IndexMap<_, _>>();
+        let (key, value) = map.random_entry().unwrap();
+        assert_eq!(map.get(key), Some(value));
+
+        map.shuffle();
+        assert_eq!(map.len(), 10);
+        assert!(map.iter().all(|(k, v)| *v == k * 2));
+
+        let mut set = (0..10).collect::<IndexSet<_>>();
+        assert!(set.contains(set.random_ref().unwrap()));
+
+        set.shuffle();
+        assert_eq!(set.len(), 10);
+    }
+
+    #[cfg(feature = "bevy")]
+    #[test]
+    fn bevy_hashmap_returns_matching_entries() {
+        use bevy::utils::HashMap;
+
+        let mut map = HashMap::new();
+        map.insert("spawn_a", 1);
+        map.insert("spawn_b", 2);
+
+        let (key, value) = map.random_entry().unwrap();
+        assert_eq!(map.get(key), Some(value));
+    }
+}
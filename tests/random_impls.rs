@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use rantz_random::{random_alphanumeric, random_option, random_result, Random, RandomRange};
+    use std::num::{NonZeroI8, NonZeroU8, Wrapping};
+    use std::time::Duration;
+
+    #[test]
+    fn tuples_and_arrays_are_random() {
+        let (a, b, c) = <(u8, bool, f32)>::random();
+        let _ = (a, b);
+        assert!((0.0..1.0).contains(&c));
+
+        let _: (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8) = Random::random();
+
+        let array = <[f64; 16]>::random();
+        assert!(array.iter().all(|v| (0.0..1.0).contains(v)));
+    }
+
+    #[test]
+    fn tuples_and_arrays_range_component_wise() {
+        for _ in 0..1000 {
+            let (a, b) = <(i32, f32)>::random_range((-5, 10.0), (5, 20.0));
+            assert!((-5..=5).contains(&a));
+            assert!((10.0..=20.0).contains(&b));
+
+            let array = <[u8; 3]>::random_range([0, 10, 20], [5, 15, 25]);
+            assert!(array[0] <= 5);
+            assert!((10..=15).contains(&array[1]));
+            assert!((20..=25).contains(&array[2]));
+        }
+    }
+
+    #[test]
+    fn option_respects_probability() {
+        assert_eq!(random_option::<u8>(0.0), None);
+        assert!(random_option::<u8>(1.0).is_some());
+
+        let somes = (0..10000)
+            .filter(|_| random_option::<u8>(0.25).is_some())
+            .count();
+        assert!((2200..2800).contains(&somes));
+    }
+
+    #[test]
+    fn result_respects_probability() {
+        assert!(random_result::<u8, bool>(1.0).is_ok());
+        assert!(random_result::<u8, bool>(0.0).is_err());
+        let _ = Result::<u8, u8>::random();
+    }
+
+    #[test]
+    fn chars_are_valid_and_ranged() {
+        for _ in 0..1000 {
+            assert!(char::from_u32(char::random() as u32).is_some());
+            assert!(random_alphanumeric().is_ascii_alphanumeric());
+            assert!(('a'..='f').contains(&char::random_range('a', 'f')));
+        }
+    }
+
+    #[test]
+    fn nonzero_ranges_skip_zero() {
+        let min = NonZeroI8::new(-1).unwrap();
+        let max = NonZeroI8::new(1).unwrap();
+        let mut seen = [false; 2];
+        for _ in 0..1000 {
+            match NonZeroI8::random_range(min, max).get() {
+                -1 => seen[0] = true,
+                1 => seen[1] = true,
+                other => panic!("{} is out of range", other),
+            }
+            let _ = NonZeroI8::random();
+        }
+        assert_eq!(seen, [true, true]);
+
+        let min = NonZeroU8::new(3).unwrap();
+        let max = NonZeroU8::new(4).unwrap();
+        assert!((3..=4).contains(&NonZeroU8::random_range(min, max).get()));
+    }
+
+    #[test]
+    fn nonzero_random_covers_extremes() {
+        let mut seen = [false; 2];
+        for _ in 0..10000 {
+            match NonZeroI8::random().get() {
+                i8::MIN => seen[0] = true,
+                i8::MAX => seen[1] = true,
+                _ => {}
+            }
+        }
+        assert_eq!(seen, [true, true]);
+    }
+
+    #[test]
+    fn durations_are_ranged() {
+        let min = Duration::from_millis(500);
+        let max = Duration::from_secs(2);
+        for _ in 0..1000 {
+            assert!((min..=max).contains(&Duration::random_range(min, max)));
+            assert!(Duration::random().subsec_nanos() < 1_000_000_000);
+        }
+    }
+
+    #[test]
+    fn wrapping_delegates() {
+        let Wrapping(v) = Wrapping::<u8>::random_range(Wrapping(5), Wrapping(6));
+        assert!((5..=6).contains(&v));
+        let _ = Wrapping::<u8>::random();
+    }
+}
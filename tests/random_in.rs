@@ -0,0 +1,210 @@
+#[cfg(test)]
+mod tests {
+    use rantz_random::RandomIn;
+    use std::ops::Bound;
+    use std::time::Duration;
+
+    #[test]
+    fn integer_ranges_follow_range_syntax() {
+        let mut seen = [false; 3];
+        for _ in 0..1000 {
+            let exclusive = u8::random_in(0..3);
+            assert!(exclusive < 3);
+            seen[exclusive as usize] = true;
+
+            assert!((0..=3).contains(&u8::random_in(0..=3)));
+            assert!(i8::random_in(120..) >= 120);
+            assert!(i8::random_in(..-120) < -120);
+            assert!(isize::random_in(..=0) <= 0);
+            let _ = i128::random_in(..);
+        }
+        assert_eq!(seen, [true; 3]);
+    }
+
+    #[test]
+    fn excluded_start_bounds_are_respected() {
+        for _ in 0..1000 {
+            let value = u32::random_in((Bound::Excluded(5), Bound::Included(6)));
+            assert_eq!(value, 6);
+        }
+    }
+
+    #[test]
+    fn half_open_float_ranges_never_return_the_end() {
+        let next = f32::from_bits(1.0f32.to_bits() + 1);
+        for _ in 0..1000 {
+            assert_eq!(f32::random_in(1.0..next), 1.0);
+            assert!(f64::random_in(0.0..1.0) < 1.0);
+        }
+    }
+
+    #[test]
+    fn inclusive_float_ranges_can_return_the_end() {
+        let next = f32::from_bits(1.0f32.to_bits() + 1);
+        let mut seen = [false; 2];
+        for _ in 0..1000 {
+            let value = f32::random_in(1.0..=next);
+            seen[0] |= value == 1.0;
+            seen[1] |= value == next;
+        }
+        assert_eq!(seen, [true, true]);
+        assert_eq!(f64::random_in(2.5..=2.5), 2.5);
+    }
+
+    #[test]
+    fn excluded_float_start_is_respected() {
+        let next = f64::from_bits(1.0f64.to_bits() + 1);
+        for _ in 0..1000 {
+            assert_eq!(
+                f64::random_in((Bound::Excluded(1.0), Bound::Included(next))),
+                next
+            );
+        }
+    }
+
+    #[test]
+    fn open_float_ranges_are_finite() {
+        for _ in 0..1000 {
+            assert!(f32::random_in(..).is_finite());
+            assert!(f64::random_in(..).is_finite());
+            assert!(f32::random_in(0.0..) >= 0.0);
+            assert!(f32::random_in(..0.0) < 0.0);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_float_range_panics() {
+        f32::random_in(1.0..1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn adjacent_excluded_float_bounds_panic() {
+        f32::random_in((Bound::Excluded(0.0), Bound::Excluded(f32::from_bits(1))));
+    }
+
+    #[test]
+    fn one_float_between_excluded_bounds() {
+        let (low, high) = (1.0f64, f64::from_bits(1.0f64.to_bits() + 2));
+        let middle = f64::from_bits(1.0f64.to_bits() + 1);
+
+        for _ in 0..100 {
+            assert_eq!(
+                f64::random_in((Bound::Excluded(low), Bound::Excluded(high))),
+                middle
+            );
+            assert_eq!(
+                f32::random_in((
+                    Bound::Excluded(-f32::from_bits(1)),
+                    Bound::Excluded(f32::from_bits(1))
+                )),
+                0.0
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_integer_range_panics() {
+        u32::random_in(5..5);
+    }
+
+    #[test]
+    fn durations_follow_range_syntax() {
+        let min = Duration::from_nanos(10);
+        let max = Duration::from_nanos(11);
+        for _ in 0..1000 {
+            assert_eq!(Duration::random_in(min..max), min);
+            assert!(Duration::random_in(..=max) <= max);
+        }
+    }
+
+    #[cfg(feature = "bevy")]
+    #[test]
+    fn vector_bounds_apply_per_component() {
+        use bevy::prelude::*;
+
+        for _ in 0..1000 {
+            let v = Vec2::random_in(Vec2::new(0.0, 10.0)..Vec2::new(1.0, 20.0));
+            assert!((0.0..1.0).contains(&v.x));
+            assert!((10.0..20.0).contains(&v.y));
+
+            let v = IVec3::random_in(IVec3::new(-1, 0, 5)..IVec3::new(0, 2, 6));
+            assert_eq!(v.x, -1);
+            assert!((0..2).contains(&v.y));
+            assert_eq!(v.z, 5);
+
+            let v = UVec2::random_in(UVec2::new(3, 4)..=UVec2::new(3, 4));
+            assert_eq!(v, UVec2::new(3, 4));
+        }
+    }
+
+    #[cfg(feature = "spatial2d")]
+    #[test]
+    fn angle_ranges_follow_range_syntax() {
+        use rantz_spatial2d::prelude::*;
+
+        for _ in 0..1000 {
+            let angle = Degrees::random_in(..).to_f32();
+            assert!((0.0..360.0).contains(&angle));
+            assert!(Radians::random_in(..).to_f32() < std::f32::consts::TAU);
+        }
+        let point = Degrees::new(45.0);
+        assert_eq!(Degrees::random_in(point..=point).to_f32(), 45.0);
+    }
+
+    #[cfg(feature = "spatial2d")]
+    #[test]
+    #[should_panic]
+    fn empty_angle_range_panics() {
+        use rantz_spatial2d::prelude::*;
+
+        Degrees::random_in(Degrees::new(10.0)..Degrees::new(10.0));
+    }
+
+    #[cfg(feature = "spatial2d")]
+    #[test]
+    #[should_panic]
+    fn adjacent_excluded_angle_bounds_panic() {
+        use rantz_spatial2d::prelude::*;
+
+        let next = f32::from_bits(10.0f32.to_bits() + 1);
+        Degrees::random_in((
+            Bound::Excluded(Degrees::new(10.0)),
+            Bound::Excluded(Degrees::new(next)),
+        ));
+    }
+
+    #[cfg(feature = "spatial2d")]
+    #[test]
+    #[should_panic]
+    fn position_ranges_check_each_axis() {
+        use rantz_spatial2d::prelude::*;
+
+        Position2D::random_in(Position2D::new(0.0, 5.0)..Position2D::new(1.0, 4.0));
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derive_accepts_any_range_syntax() {
+        use rantz_random::Random;
+
+        #[derive(Random)]
+        struct Spawn {
+            #[random(range = 0..4)]
+            lane: usize,
+            #[random(range = 0.5..1.0)]
+            scale: f32,
+            #[random(range = 10..)]
+            wave: u8,
+        }
+
+        for _ in 0..1000 {
+            let spawn = Spawn::random();
+            assert!(spawn.lane < 4);
+            assert!((0.5..1.0).contains(&spawn.scale));
+            assert!(spawn.wave >= 10);
+        }
+    }
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "bevy")]
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use rantz_random::Rng;
+    use rantz_random::{
+        Distribution, Random, RandomDirection, RandomRotation, RotationRange, TransformRange,
+    };
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    const SAMPLES: usize = 10000;
+    const EPSILON: f32 = 1e-4;
+
+    #[test]
+    fn directions_are_unit_length() {
+        for _ in 0..1000 {
+            assert!((Dir2::random().length() - 1.0).abs() < EPSILON);
+            assert!((Dir3::random().length() - 1.0).abs() < EPSILON);
+            assert!((Dir3A::random().length() - 1.0).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn directions_cover_the_sphere_evenly() {
+        rantz_random::seed(1);
+        let mut octants = [0; 8];
+        for _ in 0..SAMPLES {
+            let d = Dir3::random();
+            octants[(d.x > 0.0) as usize * 4 + (d.y > 0.0) as usize * 2 + (d.z > 0.0) as usize] +=
+                1;
+        }
+
+        for count in octants {
+            assert!((count as f32 / SAMPLES as f32 - 0.125).abs() < 0.02);
+        }
+    }
+
+    #[test]
+    fn rotations_are_uniform() {
+        rantz_random::seed(2);
+        // A uniform rotation sends any fixed vector to a uniform direction
+        let mut up = 0;
+        let mut mean = Vec3::ZERO;
+        for _ in 0..SAMPLES {
+            let q = Quat::random();
+            assert!(q.is_normalized());
+            let moved = q * Vec3::X;
+            mean += moved;
+            up += (moved.y > 0.5) as usize;
+        }
+
+        assert!((mean / SAMPLES as f32).length() < 0.03);
+        // The cap above y = 0.5 is a quarter of the sphere
+        assert!((up as f32 / SAMPLES as f32 - 0.25).abs() < 0.02);
+    }
+
+    #[test]
+    fn rotation_matrices_are_orthonormal() {
+        for _ in 0..1000 {
+            let m2 = Mat2::random_rotation();
+            assert!((m2.determinant() - 1.0).abs() < EPSILON);
+            assert!((m2 * Vec2::X).is_normalized());
+
+            let m3 = Mat3::random_rotation();
+            assert!((m3.determinant() - 1.0).abs() < EPSILON);
+            assert!((m3 * m3.transpose()).abs_diff_eq(Mat3::IDENTITY, EPSILON));
+
+            assert!(Quat::random_rotation().is_normalized());
+            assert!((Rot2::random_rotation().length() - 1.0).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn cone_directions_stay_within_the_angle() {
+        let axis = Dir3::new(Vec3::new(1.0, 1.0, 0.0)).unwrap();
+        let mut near = 0;
+        for _ in 0..SAMPLES {
+            let d = Dir3::random_in_cone(axis, FRAC_PI_4);
+            let angle = d.angle_between(*axis);
+            assert!(angle <= FRAC_PI_4 + EPSILON);
+            near += (angle.cos() > (FRAC_PI_4.cos() + 1.0) / 2.0) as usize;
+
+            let d = Dir2::random_in_cone(Dir2::Y, FRAC_PI_2);
+            assert!(d.y >= -EPSILON);
+        }
+
+        // Half of the cap's height, and so half of its area, is nearer the axis
+        assert!((near as f32 / SAMPLES as f32 - 0.5).abs() < 0.02);
+
+        let wide = (0..1000)
+            .map(|_| Dir3::random_in_cone(Dir3::Y, PI * 2.0))
+            .filter(|d| d.y < 0.0)
+            .count();
+        assert!(wide > 400);
+    }
+
+    #[test]
+    fn transforms_follow_their_ranges() {
+        let range = TransformRange::new()
+            .with_translation(Vec3::new(-1.0, 0.0, 5.0), Vec3::new(1.0, 2.0, 6.0))
+            .with_rotation(RotationRange::Around {
+                axis: Dir3::Y,
+                min: 0.0,
+                max: FRAC_PI_2,
+            })
+            .with_scale_per_axis(Vec3::ONE, Vec3::new(2.0, 3.0, 4.0));
+        let mut rng = Rng::with_seed(3);
+        for _ in 0..1000 {
+            let transform = range.sample(&mut rng);
+            assert!(transform.translation.cmpge(Vec3::new(-1.0, 0.0, 5.0)).all());
+            assert!(transform.translation.cmple(Vec3::new(1.0, 2.0, 6.0)).all());
+
+            let (axis, angle) = transform.rotation.to_axis_angle();
+            assert!(angle <= FRAC_PI_2 + EPSILON);
+            assert!(angle < EPSILON || axis.abs_diff_eq(Vec3::Y, EPSILON));
+
+            assert!(transform.scale.cmpge(Vec3::ONE).all());
+            assert!(transform.scale.cmple(Vec3::new(2.0, 3.0, 4.0)).all());
+        }
+
+        assert_eq!(TransformRange::default().sample_random(), Transform::IDENTITY);
+    }
+}
@@ -0,0 +1,127 @@
+#![cfg(feature = "bevy")]
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use rantz_random::shapes::{PolygonSampler, RandomPoint2d};
+    use rantz_random::Rng;
+
+    const SAMPLES: usize = 10000;
+    const EPSILON: f32 = 1e-4;
+
+    #[test]
+    fn circle_interior_is_uniform_by_area() {
+        let circle = Circle::new(2.0);
+        let mut rng = Rng::with_seed(1);
+        let mut inner = 0;
+        for _ in 0..SAMPLES {
+            let point = circle.random_interior_with(&mut rng);
+            assert!(point.length() <= 2.0 + EPSILON);
+            inner += (point.length() < 1.0) as usize;
+        }
+
+        // The inner circle holds a quarter of the area
+        assert!((inner as f32 / SAMPLES as f32 - 0.25).abs() < 0.02);
+    }
+
+    #[test]
+    fn boundaries_lie_on_the_edge() {
+        let mut rng = Rng::with_seed(2);
+        for _ in 0..1000 {
+            let circle = Circle::new(3.0).random_boundary_with(&mut rng);
+            assert!((circle.length() - 3.0).abs() < EPSILON);
+
+            let ring = Annulus::new(1.0, 2.0).random_boundary_with(&mut rng);
+            assert!((ring.length() - 1.0).abs() < EPSILON || (ring.length() - 2.0).abs() < EPSILON);
+
+            let rectangle = Rectangle::new(4.0, 2.0).random_boundary_with(&mut rng);
+            assert!(
+                (rectangle.x.abs() - 2.0).abs() < EPSILON
+                    || (rectangle.y.abs() - 1.0).abs() < EPSILON
+            );
+
+            let capsule = Capsule2d::new(1.0, 4.0).random_boundary_with(&mut rng);
+            let nearest = Vec2::new(0.0, capsule.y.clamp(-2.0, 2.0));
+            assert!((capsule.distance(nearest) - 1.0).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn interiors_stay_inside() {
+        let mut rng = Rng::with_seed(3);
+        let triangle = Triangle2d::new(Vec2::ZERO, Vec2::new(4.0, 0.0), Vec2::new(0.0, 4.0));
+        for _ in 0..SAMPLES {
+            let ring = Annulus::new(1.0, 2.0).random_interior_with(&mut rng);
+            assert!((1.0 - EPSILON..=2.0 + EPSILON).contains(&ring.length()));
+
+            let rectangle = Rectangle::new(4.0, 2.0).random_interior_with(&mut rng);
+            assert!(rectangle.x.abs() <= 2.0 && rectangle.y.abs() <= 1.0);
+
+            let point = triangle.random_interior_with(&mut rng);
+            assert!(point.x >= 0.0 && point.y >= 0.0 && point.x + point.y <= 4.0 + EPSILON);
+
+            let capsule = Capsule2d::new(1.0, 4.0).random_interior_with(&mut rng);
+            let nearest = Vec2::new(0.0, capsule.y.clamp(-2.0, 2.0));
+            assert!(capsule.distance(nearest) <= 1.0 + EPSILON);
+        }
+    }
+
+    #[test]
+    fn concave_polygons_are_weighted_by_area() {
+        // An L made of three unit squares, in clockwise order
+        let l_shape = PolygonSampler::new(&[
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.0, 2.0),
+            Vec2::new(1.0, 2.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(2.0, 0.0),
+        ])
+        .unwrap();
+        assert!((l_shape.area() - 3.0).abs() < EPSILON);
+        assert_eq!(l_shape.triangles().len(), 4);
+
+        let mut rng = Rng::with_seed(4);
+        let mut squares = [0; 3];
+        for _ in 0..SAMPLES {
+            let point = l_shape.random_interior_with(&mut rng);
+            assert!(!(point.x > 1.0 + EPSILON && point.y > 1.0 + EPSILON));
+            let square = if point.y > 1.0 {
+                2
+            } else if point.x > 1.0 {
+                1
+            } else {
+                0
+            };
+            squares[square] += 1;
+
+            let edge = l_shape.random_boundary_with(&mut rng);
+            assert!([0.0, 1.0, 2.0]
+                .iter()
+                .any(|line| (edge.x - line).abs() < EPSILON || (edge.y - line).abs() < EPSILON));
+        }
+
+        for count in squares {
+            assert!((count as f32 / SAMPLES as f32 - 1.0 / 3.0).abs() < 0.02);
+        }
+    }
+
+    #[test]
+    fn degenerate_polygons_are_rejected() {
+        assert!(PolygonSampler::new(&[Vec2::ZERO, Vec2::X]).is_none());
+        assert!(PolygonSampler::new(&[Vec2::ZERO, Vec2::X, Vec2::X * 2.0]).is_none());
+    }
+
+    #[test]
+    fn seeded_points_are_reproducible() {
+        let mut a = Rng::with_seed(5);
+        let mut b = Rng::with_seed(5);
+
+        for _ in 0..100 {
+            assert_eq!(
+                Circle::new(1.0).random_interior_with(&mut a),
+                Circle::new(1.0).random_interior_with(&mut b)
+            );
+        }
+    }
+}
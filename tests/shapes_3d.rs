@@ -0,0 +1,174 @@
+#![cfg(feature = "bevy")]
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::bounding::Aabb3d;
+    use bevy::prelude::*;
+    use rantz_random::shapes::{Hemisphere, MeshSampler, RandomPoint3d};
+    use rantz_random::Rng;
+
+    const SAMPLES: usize = 10000;
+    const EPSILON: f32 = 1e-4;
+
+    fn share(mut test: impl FnMut() -> bool) -> f32 {
+        (0..SAMPLES).filter(|_| test()).count() as f32 / SAMPLES as f32
+    }
+
+    #[test]
+    fn sphere_interior_is_uniform_by_volume() {
+        let sphere = Sphere::new(2.0);
+        let mut rng = Rng::with_seed(1);
+
+        // The inner ball holds an eighth of the volume
+        let inner = share(|| {
+            let point = sphere.random_interior_with(&mut rng);
+            assert!(point.length() <= 2.0 + EPSILON);
+            point.length() < 1.0
+        });
+        assert!((inner - 0.125).abs() < 0.02);
+    }
+
+    #[test]
+    fn sphere_surface_is_uniform() {
+        let mut rng = Rng::with_seed(2);
+        let mut octants = [0; 8];
+        for _ in 0..SAMPLES {
+            let point = Sphere::new(1.0).random_boundary_with(&mut rng);
+            assert!((point.length() - 1.0).abs() < EPSILON);
+            let octant = (point.x > 0.0) as usize * 4
+                + (point.y > 0.0) as usize * 2
+                + (point.z > 0.0) as usize;
+            octants[octant] += 1;
+        }
+
+        for count in octants {
+            assert!((count as f32 / SAMPLES as f32 - 0.125).abs() < 0.02);
+        }
+    }
+
+    #[test]
+    fn boxes_stay_in_bounds() {
+        let mut rng = Rng::with_seed(3);
+        let cuboid = Cuboid::new(2.0, 4.0, 6.0);
+        let aabb = Aabb3d::new(Vec3::new(10.0, 0.0, -5.0), Vec3::new(1.0, 2.0, 3.0));
+        for _ in 0..SAMPLES {
+            let inside = cuboid.random_interior_with(&mut rng);
+            assert!(inside.abs().cmple(cuboid.half_size).all());
+
+            let surface = cuboid.random_boundary_with(&mut rng);
+            assert!((surface.abs() - cuboid.half_size).abs().min_element() < EPSILON);
+
+            let inside = aabb.random_interior_with(&mut rng);
+            assert!(inside.cmpge(aabb.min.into()).all() && inside.cmple(aabb.max.into()).all());
+        }
+    }
+
+    #[test]
+    fn round_shapes_stay_in_bounds() {
+        let mut rng = Rng::with_seed(4);
+        let cylinder = Cylinder::new(1.0, 4.0);
+        let cone = Cone {
+            radius: 1.0,
+            height: 2.0,
+        };
+        let capsule = Capsule3d::new(1.0, 4.0);
+        for _ in 0..SAMPLES {
+            let point = cylinder.random_interior_with(&mut rng);
+            assert!(point.xz().length() <= 1.0 + EPSILON && point.y.abs() <= 2.0);
+
+            let point = cylinder.random_boundary_with(&mut rng);
+            assert!(
+                (point.xz().length() - 1.0).abs() < EPSILON
+                    || (point.y.abs() - 2.0).abs() < EPSILON
+            );
+
+            // The radius shrinks linearly from 1 at the base to 0 at the tip
+            let point = cone.random_interior_with(&mut rng);
+            assert!(point.y.abs() <= 1.0 + EPSILON);
+            assert!(point.xz().length() <= (1.0 - point.y) / 2.0 + EPSILON);
+
+            let point = cone.random_boundary_with(&mut rng);
+            let on_side = (point.xz().length() - (1.0 - point.y) / 2.0).abs() < EPSILON;
+            assert!(on_side || (point.y + 1.0).abs() < EPSILON);
+
+            let point = capsule.random_interior_with(&mut rng);
+            let nearest = Vec3::new(0.0, point.y.clamp(-2.0, 2.0), 0.0);
+            assert!(point.distance(nearest) <= 1.0 + EPSILON);
+
+            let point = capsule.random_boundary_with(&mut rng);
+            let nearest = Vec3::new(0.0, point.y.clamp(-2.0, 2.0), 0.0);
+            assert!((point.distance(nearest) - 1.0).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn cone_interior_is_uniform_by_volume() {
+        let cone = Cone {
+            radius: 1.0,
+            height: 2.0,
+        };
+        let mut rng = Rng::with_seed(5);
+
+        // The top half of the height is a similar cone with an eighth of the volume
+        let top = share(|| cone.random_interior_with(&mut rng).y > 0.0);
+        assert!((top - 0.125).abs() < 0.02);
+    }
+
+    #[test]
+    fn hemisphere_faces_its_normal() {
+        let mut rng = Rng::with_seed(6);
+        let hemisphere = Hemisphere::new(2.0, Dir3::X);
+        let mut cosines = 0.0;
+        for _ in 0..SAMPLES {
+            let point = hemisphere.random_interior_with(&mut rng);
+            assert!(point.x >= -EPSILON && point.length() <= 2.0 + EPSILON);
+
+            let point = hemisphere.random_boundary_with(&mut rng);
+            assert!(point.x >= -EPSILON && (point.length() - 2.0).abs() < EPSILON);
+
+            let point = hemisphere.random_cosine_weighted_with(&mut rng);
+            assert!(point.x >= -EPSILON && (point.length() - 2.0).abs() < EPSILON);
+            cosines += point.x / 2.0;
+        }
+
+        // Cosine weighting averages 2/3, a uniform dome only 1/2
+        assert!((cosines / SAMPLES as f32 - 2.0 / 3.0).abs() < 0.02);
+    }
+
+    #[test]
+    fn mesh_surface_is_weighted_by_area() {
+        // A 1x1 square and a 2x2 square side by side, as two triangles each
+        let positions = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(4.0, 0.0, 0.0),
+            Vec3::new(4.0, 2.0, 0.0),
+            Vec3::new(2.0, 2.0, 0.0),
+        ];
+        let indices = [0, 1, 2, 0, 2, 3, 4, 5, 6, 4, 6, 7];
+        let mesh = MeshSampler::new(&positions, &indices).unwrap();
+        assert!((mesh.area() - 5.0).abs() < EPSILON);
+
+        let mut rng = Rng::with_seed(7);
+        let small = share(|| mesh.random_surface_with(&mut rng).x <= 1.0);
+        assert!((small - 0.2).abs() < 0.02);
+
+        assert!(MeshSampler::new(&positions, &[0, 1, 8]).is_none());
+        assert!(MeshSampler::new(&positions, &[]).is_none());
+    }
+
+    #[test]
+    fn mesh_from_bevy() {
+        let mesh = Mesh::from(Cuboid::new(2.0, 2.0, 2.0));
+        let surface = MeshSampler::from_mesh(&mesh).unwrap();
+
+        assert!((surface.area() - 24.0).abs() < EPSILON);
+        for _ in 0..100 {
+            let point = surface.random_surface();
+            assert!((point.abs().max_element() - 1.0).abs() < EPSILON);
+        }
+    }
+}
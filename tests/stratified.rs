@@ -0,0 +1,133 @@
+#![cfg(feature = "bevy")]
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use rantz_random::scatter::*;
+    use rantz_random::Rng;
+
+    #[test]
+    fn jittered_grid_has_one_point_per_cell() {
+        let bounds = Rect::new(10.0, 20.0, 30.0, 30.0);
+        let points = stratified_2d_with(&mut Rng::with_seed(1), bounds, UVec2::new(4, 2));
+
+        assert_eq!(points.len(), 8);
+        for (i, point) in points.iter().enumerate() {
+            let cell = ((*point - bounds.min) / Vec2::new(5.0, 5.0)).floor();
+            assert_eq!(cell, Vec2::new((i % 4) as f32, (i / 4) as f32));
+        }
+    }
+
+    #[test]
+    fn zero_jitter_is_a_regular_grid() {
+        let points = jittered_grid_2d(Rect::new(0.0, 0.0, 4.0, 2.0), UVec2::new(2, 1), 0.0);
+        assert_eq!(points, vec![Vec2::new(1.0, 1.0), Vec2::new(3.0, 1.0)]);
+
+        let points = jittered_grid_3d(Vec3::ZERO, Vec3::splat(2.0), UVec3::splat(2), 0.0);
+        assert_eq!(points.len(), 8);
+        assert_eq!(points[0], Vec3::splat(0.5));
+        assert_eq!(points[7], Vec3::splat(1.5));
+    }
+
+    #[test]
+    fn jitter_stays_within_its_share_of_the_cell() {
+        let points = jittered_grid_3d_with(
+            &mut Rng::with_seed(2),
+            Vec3::ZERO,
+            Vec3::splat(3.0),
+            UVec3::splat(3),
+            0.5,
+        );
+        for point in points {
+            let offset = point - (point.floor() + Vec3::splat(0.5));
+            assert!(offset.abs().max_element() <= 0.25);
+        }
+
+        let points = stratified_3d(Vec3::ZERO, Vec3::ONE, UVec3::new(1, 2, 3));
+        assert_eq!(points.len(), 6);
+    }
+
+    #[test]
+    fn latin_hypercube_fills_every_slice_once() {
+        let count = 16;
+        let points = latin_hypercube_3d_with(
+            &mut Rng::with_seed(3),
+            Vec3::ZERO,
+            Vec3::splat(count as f32),
+            count,
+        );
+
+        assert_eq!(points.len(), count);
+        for axis in 0..3 {
+            let mut slices = points
+                .iter()
+                .map(|point| point[axis].floor() as usize)
+                .collect::<Vec<_>>();
+            slices.sort();
+            assert_eq!(slices, (0..count).collect::<Vec<_>>());
+        }
+
+        let points = latin_hypercube_2d(Rect::new(0.0, 0.0, 1.0, 1.0), 5);
+        assert_eq!(points.len(), 5);
+    }
+
+    #[test]
+    fn halton_matches_known_values() {
+        let points = Halton2d::new().take(4).collect::<Vec<_>>();
+
+        assert_eq!(
+            points,
+            vec![
+                Vec2::new(0.5, 1.0 / 3.0),
+                Vec2::new(0.25, 2.0 / 3.0),
+                Vec2::new(0.75, 1.0 / 9.0),
+                Vec2::new(0.125, 4.0 / 9.0),
+            ]
+        );
+        assert_eq!(Halton3d::new().next(), Some(Vec3::new(0.5, 1.0 / 3.0, 0.2)));
+    }
+
+    #[test]
+    fn sobol_matches_known_values() {
+        let points = Sobol3d::new().take(4).collect::<Vec<_>>();
+
+        assert_eq!(
+            points,
+            vec![
+                Vec3::new(0.0, 0.0, 0.0),
+                Vec3::new(0.5, 0.5, 0.5),
+                Vec3::new(0.75, 0.25, 0.25),
+                Vec3::new(0.25, 0.75, 0.75),
+            ]
+        );
+    }
+
+    #[test]
+    fn sobol_power_of_two_batches_are_stratified() {
+        // Any 2^k points fill each of the 2^k cells of a 2^(k/2) grid exactly once
+        let points = Sobol2d::new().take(64).collect::<Vec<_>>();
+        let mut cells = points
+            .iter()
+            .map(|point| ((point.x * 8.0) as usize, (point.y * 8.0) as usize))
+            .collect::<Vec<_>>();
+        cells.sort();
+        cells.dedup();
+
+        assert_eq!(cells.len(), 64);
+    }
+
+    #[test]
+    fn randomized_sequences_stay_in_the_unit_square() {
+        let mut rng = Rng::with_seed(4);
+        let halton = Halton2d::new().randomized_with(&mut rng);
+        let sobol = Sobol3d::new().randomized_with(&mut rng);
+
+        assert_ne!(halton.clone().next(), Halton2d::new().next());
+        for point in halton.take(1000) {
+            assert!(point.cmpge(Vec2::ZERO).all() && point.cmplt(Vec2::ONE).all());
+        }
+        for point in sobol.take(1000) {
+            assert!(point.cmpge(Vec3::ZERO).all() && point.cmplt(Vec3::ONE).all());
+        }
+    }
+}
@@ -0,0 +1,173 @@
+#![cfg(feature = "bevy")]
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use rantz_random::walk::*;
+    use rantz_random::{Rng, WeightedTable};
+
+    #[test]
+    fn grid_walks_start_at_start_and_step_to_neighbours() {
+        let start = IVec2::new(3, -2);
+        let path: Vec<IVec2> = GridWalk2d::new(start, GridNeighbours::Orthogonal)
+            .with_rng(&mut Rng::with_seed(1))
+            .take(500)
+            .collect();
+
+        assert_eq!(path[0], start);
+        for pair in path.windows(2) {
+            assert_eq!((pair[1] - pair[0]).abs().element_sum(), 1);
+        }
+    }
+
+    #[test]
+    fn diagonal_grid_walks_use_every_neighbour() {
+        let path: Vec<IVec3> = GridWalk3d::new(IVec3::ZERO, GridNeighbours::WithDiagonals)
+            .with_rng(&mut Rng::with_seed(2))
+            .take(5000)
+            .collect();
+
+        let mut steps = path
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .collect::<Vec<_>>();
+        assert!(steps.iter().all(|step| step.abs().max_element() == 1));
+        steps.sort_by_key(|step| step.to_array());
+        steps.dedup();
+        assert_eq!(steps.len(), 26);
+    }
+
+    #[test]
+    fn grid_walks_stay_in_bounds() {
+        let bounds = IRect::new(0, 0, 4, 4);
+        let walk = GridWalk2d::new(IVec2::new(10, 10), GridNeighbours::WithDiagonals)
+            .with_bounds(bounds)
+            .with_rng(&mut Rng::with_seed(3));
+
+        assert_eq!(walk.position(), IVec2::new(4, 4));
+        for cell in walk.take(1000) {
+            assert!(bounds.contains(cell));
+        }
+
+        let walk = GridWalk3d::new(IVec3::ZERO, GridNeighbours::Orthogonal)
+            .with_bounds(IVec3::ZERO, IVec3::splat(2))
+            .with_rng(&mut Rng::with_seed(3));
+        for cell in walk.take(1000) {
+            assert!(cell.cmpge(IVec3::ZERO).all() && cell.cmple(IVec3::splat(2)).all());
+        }
+    }
+
+    #[test]
+    fn weighted_grid_walks_follow_weights() {
+        let steps: WeightedTable<IVec2> = [(IVec2::X, 3), (IVec2::NEG_X, 1), (IVec2::Y, 0)]
+            .into_iter()
+            .collect();
+        let end = GridWalk2d::weighted(IVec2::ZERO, &steps)
+            .unwrap()
+            .with_rng(&mut Rng::with_seed(4))
+            .nth(4000)
+            .unwrap();
+
+        assert_eq!(end.y, 0);
+        assert!((1700..2300).contains(&end.x));
+
+        assert!(GridWalk2d::weighted(IVec2::ZERO, &WeightedTable::new()).is_none());
+        assert!(GridWalk3d::weighted(IVec3::ZERO, &WeightedTable::new()).is_none());
+    }
+
+    #[cfg(feature = "spatial2d")]
+    #[test]
+    fn compass_walks_step_towards_their_points() {
+        use rantz_spatial2d::prelude::CompassRose;
+
+        let directions: WeightedTable<CompassRose> = [(CompassRose::NE, 1), (CompassRose::S, 0)]
+            .into_iter()
+            .collect();
+        let path: Vec<IVec2> = GridWalk2d::compass(IVec2::ZERO, &directions)
+            .unwrap()
+            .take(5)
+            .collect();
+
+        assert_eq!(path[4], IVec2::splat(4));
+    }
+
+    #[test]
+    fn walks_replay_from_a_seed() {
+        let walk = |seed| {
+            BrownianMotion3d::new(Vec3::ZERO, 1.0)
+                .with_rng(&mut Rng::with_seed(seed))
+                .take(20)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(walk(5), walk(5));
+        assert_ne!(walk(5), walk(6));
+    }
+
+    #[test]
+    fn brownian_motion_spreads_with_the_square_root_of_time() {
+        let mut rng = Rng::with_seed(7);
+        let mut spread = 0.0;
+        let mut drift = Vec2::ZERO;
+        for _ in 0..500 {
+            let end = BrownianMotion2d::new(Vec2::ZERO, 0.5)
+                .with_drift(Vec2::new(0.1, 0.0))
+                .with_rng(&mut rng)
+                .nth(100)
+                .unwrap();
+            drift += end / 500.0;
+            spread += (end.y * end.y) / 500.0;
+        }
+
+        // 100 steps of standard deviation 0.5 is a variance of 25 on each axis
+        assert!((20.0..30.0).contains(&spread));
+        assert!((9.0..11.0).contains(&drift.x));
+    }
+
+    #[test]
+    fn correlated_walks_turn_gradually() {
+        let walk = CorrelatedWalk2d::new(Vec2::ZERO, 2.0, 0.2)
+            .with_rng(&mut Rng::with_seed(8))
+            .with_heading(Dir2::Y);
+        assert_eq!(walk.heading(), Dir2::Y);
+
+        let path: Vec<Vec2> = walk.take(200).collect();
+        for window in path.windows(3) {
+            let (a, b) = (window[1] - window[0], window[2] - window[1]);
+            assert!((a.length() - 2.0).abs() < 1e-3);
+            assert!(a.angle_between(b).abs() <= 0.2 + 1e-3);
+        }
+
+        let path: Vec<Vec3> = CorrelatedWalk3d::new(Vec3::ZERO, 1.0, 0.3)
+            .with_rng(&mut Rng::with_seed(9))
+            .take(200)
+            .collect();
+        for window in path.windows(3) {
+            let (a, b) = (window[1] - window[0], window[2] - window[1]);
+            assert!((b.length() - 1.0).abs() < 1e-3);
+            assert!(a.angle_between(b) <= 0.3 + 1e-3);
+        }
+    }
+
+    #[test]
+    fn levy_flight_steps_are_bounded() {
+        let path: Vec<Vec3> = LevyFlight3d::new(Vec3::ZERO, 0.5, 1.2)
+            .unwrap()
+            .with_max_step(10.0)
+            .with_rng(&mut Rng::with_seed(10))
+            .take(2000)
+            .collect();
+
+        let lengths = path
+            .windows(2)
+            .map(|pair| pair[0].distance(pair[1]))
+            .collect::<Vec<_>>();
+        assert!(lengths
+            .iter()
+            .all(|&l| (0.5 - 1e-3..=10.0 + 1e-3).contains(&l)));
+        assert!(lengths.iter().any(|&l| l > 5.0));
+        assert!(lengths.iter().filter(|&&l| l < 1.0).count() > 1000);
+
+        assert!(LevyFlight2d::new(Vec2::ZERO, 0.0, 1.5).is_none());
+        assert!(LevyFlight2d::new(Vec2::ZERO, 1.0, -1.0).is_none());
+    }
+}